@@ -2003,7 +2003,218 @@ that refers to itself. That is permitting, since the closure would be
 invoking itself via a virtual call, and hence does not directly
 reference its own *type*.
 
-"##, }
+"##,
+
+E0689: r##"
+A `#[repr(..)]` hint which does not take any arguments was given some.
+
+Erroneous code example:
+
+```compile_fail,E0689
+#[repr(C(x))] // error: the `C` representation hint takes no arguments
+struct Foo {
+    bar: u32,
+}
+```
+
+Hints such as `C`, `simd` or the integer types are plain words, so remove the
+arguments:
+
+```
+#[repr(C)] // ok!
+struct Foo {
+    bar: u32,
+}
+```
+"##,
+
+E0690: r##"
+An `#[inline]` attribute was given a value.
+
+Erroneous code example:
+
+```
+#[inline = "always"] // warning: the `inline` attribute does not take a value
+fn foo() {}
+```
+
+The value is ignored, so this attribute does not do what it looks like it
+does. This is a warning rather than an error for now, as such attributes used
+to be accepted silently. Use the list form to pass an argument to `#[inline]`:
+
+```
+#[inline(always)] // ok!
+fn foo() {}
+```
+"##,
+
+E0691: r##"
+A `#[thread_local]` attribute was applied to something other than a static.
+
+Erroneous code example:
+
+```compile_fail,E0691
+#![feature(thread_local)]
+
+#[thread_local] // error: attribute should be applied to a static
+fn foo() {}
+```
+
+Only statics have storage that can be made thread-local:
+
+```
+#![feature(thread_local)]
+
+#[thread_local] // ok!
+static FOO: u32 = 0;
+```
+"##,
+
+E0692: r##"
+A `#[thread_local]` attribute was given arguments or a value.
+
+Erroneous code example:
+
+```compile_fail,E0692
+#![feature(thread_local)]
+
+#[thread_local(always)] // error: the `thread_local` attribute takes no arguments
+static FOO: u32 = 0;
+```
+
+`#[thread_local]` has no options, so use it as a plain word:
+
+```
+#![feature(thread_local)]
+
+#[thread_local] // ok!
+static FOO: u32 = 0;
+```
+"##,
+
+E0693: r##"
+A single `#[repr(..)]` attribute asked for more than one alignment.
+
+Erroneous code example:
+
+```compile_fail,E0693
+#![feature(attr_literals)]
+#![feature(repr_align)]
+
+#[repr(align(8), align(16))] // error: conflicting `align` representation hints
+struct Foo(u32);
+```
+
+Only one alignment can apply, so keep the one you meant:
+
+```
+#![feature(attr_literals)]
+#![feature(repr_align)]
+
+#[repr(align(16))] // ok!
+struct Foo(u32);
+```
+"##,
+
+E0694: r##"
+A `#[main]` attribute was applied to something other than a function.
+
+Erroneous code example:
+
+```
+#![feature(main)]
+
+#[main] // warning: attribute should be applied to a function
+struct Foo;
+
+fn main() {}
+```
+
+This is a warning rather than an error for now, as such attributes used to
+only be reported as unused.
+
+`#[main]` marks the function to use as the entry point of the program:
+
+```
+#![feature(main)]
+
+#[main] // ok!
+fn start() {}
+```
+"##,
+
+E0695: r##"
+A `#[lang]` attribute was not in the `#[lang = "name"]` form.
+
+Erroneous code example:
+
+```compile_fail,E0695
+#![feature(lang_items)]
+
+#[lang] // error: malformed `lang` attribute
+fn foo() {}
+```
+
+The attribute names the lang item the item provides, so it needs a value, as
+in `#[lang = "panic_fmt"]`.
+"##,
+
+E0696: r##"
+A `#[repr(packed(N))]` attribute was given an invalid packing.
+
+Erroneous code example:
+
+```compile_fail,E0696
+#![feature(attr_literals, repr_packed)]
+
+#[repr(packed(0))] // error: invalid `repr(packed)` attribute
+struct Foo {
+    a: u8,
+    b: u32,
+}
+```
+
+The packing is the largest alignment any field of the type may have, so it must
+be a power of two of at least 1. Only `packed(1)`, which means the same as
+`packed`, is currently supported:
+
+```
+#[repr(packed)]
+struct Foo {
+    a: u8,
+    b: u32,
+}
+```
+"##,
+
+E0697: r##"
+A `#[cold]` attribute was applied to a closure.
+
+Erroneous code example:
+
+```compile_fail,E0697
+#![feature(stmt_expr_attributes)]
+
+fn main() {
+    let f = #[cold] || (); // error: attribute should be applied to function
+}
+```
+
+The attribute marks a function as rarely called, so calls to it are optimized
+for the common case not taking them. Move the rarely called code into a
+function and mark that instead:
+
+```
+#[cold]
+fn report() {}
+
+fn main() {
+    let f = || report();
+}
+```
+"##,
+
+}
 
 
 register_diagnostics! {
@@ -9,23 +9,66 @@
 // except according to those terms.
 
 //! This module implements some validity checks for attributes.
-//! In particular it verifies that `#[inline]` and `#[repr]` attributes are
-//! attached to items that actually support them and if there are
-//! conflicts between multiple such attributes attached to the same
-//! item.
+//! In particular it verifies that attributes such as `#[inline]`, `#[repr]`
+//! and `#[thread_local]` are attached to items that actually support them
+//! and if there are conflicts between multiple such attributes attached to
+//! the same item.
 
+use errors::{Diagnostic, DiagnosticBuilder, DiagnosticId, Level};
+use lint;
 use session::Session;
+use ty::layout::TargetDataLayout;
+use util::nodemap::{FxHashMap, NodeMap};
+use std::cell::RefCell;
+use std::cmp;
 
 use syntax::ast;
+use syntax::attr;
+use syntax::symbol::Symbol;
 use syntax::visit;
 use syntax::visit::Visitor;
+use syntax_pos::{MultiSpan, Span};
 
-#[derive(Copy, Clone, PartialEq)]
-enum Target {
+/// The names of the attributes this pass checks. This is only a list for
+/// `checked_attribute_names`, the dispatch in
+/// `CheckAttrVisitor::check_attribute` doesn't use it, so keep the two in
+/// sync by hand.
+const CHECKED_ATTRIBUTES: &[&str] = &["inline", "repr", "thread_local", "cold", "main", "lang"];
+
+/// The names of the attributes whose placement and arguments this pass
+/// validates.
+pub fn checked_attribute_names() -> &'static [&'static str] {
+    CHECKED_ATTRIBUTES
+}
+
+/// The kinds of nodes attributes are checked on.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Target {
     Fn,
     Struct,
     Union,
     Enum,
+    Const,
+    Static,
+    TyAlias,
+    ImplTraitAlias,
+    MacroDef,
+    TraitAlias,
+    GlobalAsm,
+    ForeignMod,
+    Use,
+    ExternCrate,
+    Variant,
+    Closure,
+    Expression,
+    Arm,
+    Statement,
+    GenericParam,
+    Method,
+    AssocConst,
+    AssocTy,
+    ForeignFn,
+    ForeignStatic,
     Other,
 }
 
@@ -36,64 +79,495 @@ impl Target {
             ast::ItemKind::Struct(..) => Target::Struct,
             ast::ItemKind::Union(..) => Target::Union,
             ast::ItemKind::Enum(..) => Target::Enum,
+            ast::ItemKind::Const(..) => Target::Const,
+            ast::ItemKind::Static(..) => Target::Static,
+            ast::ItemKind::Ty(ref ty, _) => match ty.node {
+                ast::TyKind::ImplTrait(..) => Target::ImplTraitAlias,
+                _ => Target::TyAlias,
+            },
+            ast::ItemKind::MacroDef(..) => Target::MacroDef,
+            ast::ItemKind::TraitAlias(..) => Target::TraitAlias,
+            ast::ItemKind::GlobalAsm(..) => Target::GlobalAsm,
+            ast::ItemKind::ForeignMod(..) => Target::ForeignMod,
+            ast::ItemKind::Use(..) => Target::Use,
+            ast::ItemKind::ExternCrate(..) => Target::ExternCrate,
             _ => Target::Other,
         }
     }
+
+    fn from_foreign_item(item: &ast::ForeignItem) -> Target {
+        match item.node {
+            ast::ForeignItemKind::Fn(..) => Target::ForeignFn,
+            ast::ForeignItemKind::Static(..) => Target::ForeignStatic,
+            ast::ForeignItemKind::Ty => Target::Other,
+        }
+    }
+
+    fn from_trait_item(item: &ast::TraitItem) -> Target {
+        match item.node {
+            ast::TraitItemKind::Method(..) => Target::Method,
+            ast::TraitItemKind::Const(..) => Target::AssocConst,
+            ast::TraitItemKind::Type(..) => Target::AssocTy,
+            ast::TraitItemKind::Macro(..) => Target::Other,
+        }
+    }
+
+    fn from_impl_item(item: &ast::ImplItem) -> Target {
+        match item.node {
+            ast::ImplItemKind::Method(..) => Target::Method,
+            ast::ImplItemKind::Const(..) => Target::AssocConst,
+            ast::ImplItemKind::Type(..) => Target::AssocTy,
+            ast::ImplItemKind::Macro(..) => Target::Other,
+        }
+    }
+
+    /// A human readable description of the target, suitable for use in
+    /// labels such as "not a function".
+    pub fn description(&self) -> &'static str {
+        match *self {
+            Target::Fn => "a function",
+            Target::Struct => "a struct",
+            Target::Union => "a union",
+            Target::Enum => "an enum",
+            Target::Const => "a constant",
+            Target::Static => "a static",
+            Target::TyAlias => "a type alias",
+            Target::ImplTraitAlias => "an `impl Trait` type alias",
+            Target::MacroDef => "a macro definition",
+            Target::TraitAlias => "a trait alias",
+            Target::GlobalAsm => "a global assembly block",
+            Target::ForeignMod => "an extern block",
+            Target::Use => "a `use` declaration",
+            Target::ExternCrate => "an `extern crate` item",
+            Target::Variant => "an enum variant",
+            Target::Closure => "a closure",
+            Target::Expression => "an expression",
+            Target::Arm => "a match arm",
+            Target::Statement => "a statement",
+            Target::GenericParam => "a generic parameter",
+            Target::Method => "a method",
+            Target::AssocConst => "an associated constant",
+            Target::AssocTy => "an associated type",
+            Target::ForeignFn => "a foreign function",
+            Target::ForeignStatic => "a foreign static",
+            Target::Other => "an item",
+        }
+    }
+}
+
+/// Describe a set of expected targets, e.g. "a struct, an enum or a union".
+fn describe_targets(targets: &[Target]) -> String {
+    let descriptions: Vec<_> = targets.iter().map(|t| t.description()).collect();
+    match descriptions.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} or {}", rest.join(", "), last),
+        Some((last, _)) => last.to_string(),
+        None => String::new(),
+    }
+}
+
+/// What a `CheckAttrVisitor` does with what it finds. Errors and warnings
+/// are reported after HIR lowering, but lints have to be buffered before the
+/// early lint checks, so the two are found by separate passes.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Mode {
+    /// Emit errors and warnings, and drop lints.
+    Emit,
+    /// Record errors and warnings in `collected`, and drop lints.
+    Collect,
+    /// Buffer lints for the early lint checks, and drop errors and warnings.
+    Lint,
 }
 
 struct CheckAttrVisitor<'a> {
     sess: &'a Session,
+    mode: Mode,
+    /// The attributes with conflicting representation hints, held back when
+    /// `-Z repr-conflict-summary` is given so they can be reported together.
+    repr_conflicts: Vec<Span>,
+    /// The diagnostics found in `Mode::Collect`.
+    collected: RefCell<Vec<Diagnostic>>,
+    /// The diagnostics for the node being checked. They are emitted once all
+    /// of its attributes have been checked, in the order of their spans.
+    pending: RefCell<Vec<Diagnostic>>,
+    /// When set, every attribute checked is tallied here.
+    usage: Option<AttrUsage>,
+    /// The target's data layout, parsed the first time it's needed.
+    data_layout: RefCell<Option<TargetDataLayout>>,
 }
 
 impl<'a> CheckAttrVisitor<'a> {
-    /// Check any attribute.
-    fn check_attribute(&self, attr: &ast::Attribute, item: &ast::Item, target: Target) {
-        if let Some(name) = attr.name() {
-            match &*name.as_str() {
-                "inline" => self.check_inline(attr, item, target),
-                "repr" => self.check_repr(attr, item, target),
-                _ => (),
+    fn new(sess: &'a Session, mode: Mode) -> CheckAttrVisitor<'a> {
+        CheckAttrVisitor {
+            sess: sess,
+            mode: mode,
+            repr_conflicts: Vec::new(),
+            collected: RefCell::new(Vec::new()),
+            pending: RefCell::new(Vec::new()),
+            usage: None,
+            data_layout: RefCell::new(None),
+        }
+    }
+
+    /// Emit `err` once the current node has been checked, record it, or drop
+    /// it, depending on the mode.
+    fn emit(&self, err: &mut DiagnosticBuilder) {
+        match self.mode {
+            Mode::Emit => self.pending.borrow_mut().push((**err).clone()),
+            Mode::Collect => self.collected.borrow_mut().push((**err).clone()),
+            Mode::Lint => {}
+        }
+        err.cancel();
+    }
+
+    /// Emit the diagnostics of the node just checked. The checks don't run
+    /// in source order, e.g. hints gathered across all `#[repr]` attributes
+    /// are checked after each attribute on its own, so sort them first.
+    fn emit_pending(&self) {
+        let mut pending = self.pending.borrow_mut();
+        pending.sort_by_key(|diagnostic| diagnostic.span.primary_span());
+        for diagnostic in pending.drain(..) {
+            DiagnosticBuilder::new_diagnostic(self.sess.diagnostic(), diagnostic).emit();
+        }
+    }
+
+    /// Emit an error about an attribute that is ignored on the kind of node
+    /// it's applied to, as a warning with `-Z misplaced-attrs-as-warnings`.
+    fn emit_misplaced(&self, err: &mut DiagnosticBuilder) {
+        if self.sess.opts.debugging_opts.misplaced_attrs_as_warnings {
+            err.level = Level::Warning;
+        }
+        self.emit(err);
+    }
+
+    /// The alignment on the target of the only field of a struct or union
+    /// item, if the field syntactically names a primitive.
+    fn single_primitive_field_align(&self, item: &ast::Item) -> Option<u128> {
+        let field = match item.node {
+            ast::ItemKind::Struct(ref data, _) |
+            ast::ItemKind::Union(ref data, _) if data.fields().len() == 1 => &data.fields()[0],
+            _ => return None,
+        };
+        let name = match primitive_name(&field.ty) {
+            Some(name) => name,
+            None => return None,
+        };
+        let mut data_layout = self.data_layout.borrow_mut();
+        let dl = data_layout.get_or_insert_with(|| TargetDataLayout::parse(self.sess));
+        let align = match &*name.as_str() {
+            "bool" | "i8" | "u8" => dl.i8_align,
+            "i16" | "u16" => dl.i16_align,
+            "char" | "i32" | "u32" => dl.i32_align,
+            "f32" => dl.f32_align,
+            "i64" | "u64" => dl.i64_align,
+            "f64" => dl.f64_align,
+            "i128" | "u128" => dl.i128_align,
+            "isize" | "usize" => dl.pointer_align,
+            _ => return None,
+        };
+        Some(align.abi() as u128)
+    }
+
+    fn buffer_lint<S: Into<MultiSpan>>(&self,
+                                       lint: &'static lint::Lint,
+                                       id: ast::NodeId,
+                                       span: S,
+                                       msg: &str) {
+        if self.mode == Mode::Lint {
+            self.sess.buffer_lint(lint, id, span, msg);
+        }
+    }
+
+    /// Check any attribute. `span` is the span of the node the attribute is
+    /// attached to, and `item` is that node if it is an item.
+    fn check_attribute(&mut self,
+                       attr: &ast::Attribute,
+                       span: Span,
+                       target: Target,
+                       item: Option<&ast::Item>) {
+        if let Some(symbol) = attr.name() {
+            if let Some(ref mut usage) = self.usage {
+                *usage.counts.entry((symbol, target)).or_insert(0) += 1;
+            }
+            let name = symbol.as_str();
+            // The parser never produces these, but macros building attributes
+            // by hand can, and they'd otherwise be silently ignored.
+            if name.trim().is_empty() {
+                self.emit(&mut self.sess.struct_span_err(attr.span,
+                                                         "malformed attribute: empty name"));
+                return;
+            }
+            match &*name {
+                "inline" => self.check_inline(attr, span, target, item),
+                "repr" => self.check_repr(attr, span, target, item),
+                "thread_local" => self.check_thread_local(attr, span, target),
+                "cold" => self.check_cold(attr, span, target),
+                "main" => self.check_main(attr, span, target),
+                "lang" => self.check_lang(attr),
+                _ => {}
             }
         }
     }
 
     /// Check if an `#[inline]` is applied to a function.
-    fn check_inline(&self, attr: &ast::Attribute, item: &ast::Item, target: Target) {
+    fn check_inline(&self,
+                    attr: &ast::Attribute,
+                    span: Span,
+                    target: Target,
+                    item: Option<&ast::Item>) {
+        if target != Target::Fn && target != Target::Method && target != Target::Closure {
+            let mut err = struct_span_err!(self.sess, attr.span, E0518,
+                                           "attribute should be applied to function");
+            if target == Target::Const || target == Target::AssocConst {
+                err.span_label(span, "inline applies to functions, not constants");
+            } else {
+                err.span_label(span, format!("not {}", Target::Fn.description()));
+            }
+            if target == Target::ForeignFn {
+                err.note("foreign functions are defined in another library and can't be inlined");
+            }
+            if target == Target::Use && item.map_or(false, is_reexport) {
+                err.note("attributes on a re-export don't change the items it re-exports, \
+                          they apply at the definition site");
+            }
+            self.emit_misplaced(&mut err);
+            return;
+        }
+
+        // `#[inline = "always"]` is accepted by the parser, but ignored when
+        // translating, so point people at the list form. Only a warning, as
+        // crates using it have always compiled.
+        let is_name_value = !attr.is_word() && match attr.meta() {
+            Some(ast::MetaItem { node: ast::MetaItemKind::NameValue(_), .. }) => true,
+            _ => false,
+        };
+        if is_name_value {
+            let (msg, suggestion) = match attr.value_str() {
+                Some(value) if value == "always" || value == "never" => {
+                    ("use a list instead", format!("inline({})", value))
+                }
+                _ => ("remove the value", "inline".to_string()),
+            };
+            let style = if attr.style == ast::AttrStyle::Inner { "#!" } else { "#" };
+            self.emit(struct_span_warn!(self.sess, attr.span, E0690,
+                                        "the `inline` attribute does not take a value")
+                .span_suggestion(attr.span, msg, format!("{}[{}]", style, suggestion)));
+        }
+
+        // These are the errors `attr::find_inline_attr` gives when translating,
+        // caught here as well so that functions that are never translated get
+        // them too.
+        if let Some(items) = meta_item_list(attr) {
+            if items.len() != 1 {
+                self.emit(&mut self.sess.struct_span_err_with_code(
+                    attr.span, "expected one argument", DiagnosticId::Error("E0534".to_owned())));
+            } else if !items[0].is_word() ||
+                    !(items[0].check_name("always") || items[0].check_name("never")) {
+                self.emit(self.sess.struct_span_err_with_code(
+                    items[0].span, "invalid argument", DiagnosticId::Error("E0535".to_owned()))
+                    .help("the argument must be `always` or `never`"));
+            }
+        }
+
+        // Only direct calls by name are found, which is enough for the
+        // obvious cases without needing name resolution.
+        if is_inline_always(attr) {
+            if let Some(item) = item {
+                if let ast::ItemKind::Fn(ref decl, .., ref body) = item.node {
+                    let mut finder = SelfCallFinder {
+                        name: item.ident.name,
+                        found: false,
+                        shadowed: false,
+                    };
+                    for arg in &decl.inputs {
+                        finder.visit_pat(&arg.pat);
+                    }
+                    finder.visit_block(body);
+                    if finder.found && !finder.shadowed {
+                        self.buffer_lint(lint::builtin::RECURSIVE_INLINE_ALWAYS,
+                                         item.id, attr.span,
+                                         "`#[inline(always)]` can't be honoured for a \
+                                          function that calls itself");
+                    }
+                }
+            }
+        }
+    }
+
+    /// Check that `#[cold]` isn't applied to a closure. It is accepted on
+    /// anything else for backwards compatibility.
+    fn check_cold(&self, attr: &ast::Attribute, span: Span, target: Target) {
+        if target == Target::Closure {
+            let mut err = struct_span_err!(self.sess, attr.span, E0697,
+                                           "attribute should be applied to function");
+            err.span_label(span, "a closure, not a function");
+            err.note("move the rarely called code into a `#[cold]` function instead");
+            self.emit_misplaced(&mut err);
+        }
+    }
+
+    /// Check that a function isn't both `#[cold]` and `#[inline(always)]`,
+    /// which ask for opposite treatment of its call sites.
+    fn check_cold_inline(&self, id: ast::NodeId, attrs: &[ast::Attribute]) {
+        let cold = attrs.iter().any(|attr| attr.check_name("cold"));
+        let inline = attrs.iter().find(|attr| attr.check_name("inline") && is_inline_always(attr));
+        if let (true, Some(inline)) = (cold, inline) {
+            self.buffer_lint(lint::builtin::COLD_INLINE_ALWAYS, id, inline.span,
+                             "`#[inline(always)]` contradicts `#[cold]`");
+        }
+    }
+
+    /// Check for an `#[inline(always)]` on a `#[target_feature]` function,
+    /// which LLVM won't inline into callers that lack the feature.
+    fn check_inline_target_feature(&self, id: ast::NodeId, attrs: &[ast::Attribute]) {
+        let inline = attrs.iter().find(|attr| attr.check_name("inline") && is_inline_always(attr));
+        let target_feature = attrs.iter().any(|attr| attr.check_name("target_feature"));
+        if let (Some(inline), true) = (inline, target_feature) {
+            self.buffer_lint(lint::builtin::INLINE_TARGET_FEATURE, id, inline.span,
+                             "this function has a `#[target_feature]`, so it can't be \
+                              inlined into callers that don't enable the same features");
+        }
+    }
+
+    /// Check for an `#[inline]` on a function whose body is just `{}`, which
+    /// is usually a stub that was never filled in.
+    fn check_inline_empty_fn(&self, id: ast::NodeId, attrs: &[ast::Attribute], body: &ast::Block) {
+        if !body.stmts.is_empty() {
+            return;
+        }
+        if let Some(inline) = attrs.iter().find(|attr| attr.check_name("inline")) {
+            self.buffer_lint(lint::builtin::INLINE_EMPTY_FN, id, inline.span,
+                             "this `#[inline]` function has an empty body");
+        }
+    }
+
+    /// Check for an `#[inline]` on a `#[no_mangle]` function, whose symbol is
+    /// emitted for other code to link to whether or not calls get inlined.
+    fn check_inline_no_mangle(&self, item: &ast::Item) {
+        let inline = item.attrs.iter().find(|attr| attr.check_name("inline"));
+        let no_mangle = item.attrs.iter().any(|attr| attr.check_name("no_mangle"));
+        if let (Some(inline), true) = (inline, no_mangle) {
+            self.buffer_lint(lint::builtin::INLINE_NO_MANGLE, item.id, inline.span,
+                             "this function is `#[no_mangle]`, so its symbol is still \
+                              emitted even though it's `#[inline]`");
+        }
+    }
+
+    /// Point out a `#[no_mangle]` static that is also `#[thread_local]`, since
+    /// the symbol it exports is then thread-local too.
+    fn check_no_mangle_thread_local(&self, id: ast::NodeId, attrs: &[ast::Attribute]) {
+        let no_mangle = attrs.iter().find(|attr| attr.check_name("no_mangle"));
+        let thread_local = attrs.iter().any(|attr| attr.check_name("thread_local"));
+        if let (Some(no_mangle), true) = (no_mangle, thread_local) {
+            self.buffer_lint(lint::builtin::NO_MANGLE_THREAD_LOCAL, id, no_mangle.span,
+                             "this `#[no_mangle]` static is thread-local, so the symbol it \
+                              exports is too, and not every platform can link to one");
+        }
+    }
+
+    /// Check if a `#[main]` is applied to a function. Only a warning, as it
+    /// has always been ignored anywhere else.
+    fn check_main(&self, attr: &ast::Attribute, span: Span, target: Target) {
         if target != Target::Fn {
-            struct_span_err!(self.sess, attr.span, E0518, "attribute should be applied to function")
-                .span_label(item.span, "not a function")
-                .emit();
+            self.emit(struct_span_warn!(self.sess, attr.span, E0694,
+                                        "attribute should be applied to a function")
+                .span_label(span, format!("not {}", Target::Fn.description())));
         }
     }
 
-    /// Check if an `#[repr]` attr is valid.
-    fn check_repr(&self, attr: &ast::Attribute, item: &ast::Item, target: Target) {
-        let words = match attr.meta_item_list() {
-            Some(words) => words,
-            None => {
-                return;
+    /// Check if a `#[lang]` has the `#[lang = "name"]` form. Which items can
+    /// be which lang items is checked when they are collected.
+    fn check_lang(&self, attr: &ast::Attribute) {
+        if attr.is_word() || !attr.is_value_str() {
+            self.emit(struct_span_err!(self.sess, attr.span, E0695, "malformed `lang` attribute")
+                .help("the name of the lang item is given as `#[lang = \"name\"]`"));
+        }
+    }
+
+    /// Check for an `#[inline]` on a trait method without a default body,
+    /// where there is nothing to inline.
+    fn check_required_method_inline(&self, item: &ast::TraitItem) {
+        if let ast::TraitItemKind::Method(_, None) = item.node {
+            for attr in item.attrs.iter().filter(|attr| attr.check_name("inline")) {
+                self.buffer_lint(lint::builtin::INLINE_REQUIRED_METHOD, item.id, attr.span,
+                                 "`#[inline]` has no effect on a trait method without a body, \
+                                  put it on the implementations of the method instead");
             }
-        };
+        }
+    }
+
+    /// Check for a `#[must_use]` on a method returning `()`, which leaves the
+    /// caller nothing to use.
+    fn check_must_use_unit(&self,
+                           id: ast::NodeId,
+                           attrs: &[ast::Attribute],
+                           sig: &ast::MethodSig) {
+        if !returns_unit(&sig.decl) {
+            return;
+        }
+        for attr in attrs.iter().filter(|attr| attr.check_name("must_use")) {
+            self.buffer_lint(lint::builtin::MUST_USE_UNIT, id, attr.span,
+                             "`#[must_use]` has no effect on a method that returns `()`, \
+                              there is no value for the caller to use");
+        }
+    }
+
+    /// Check if a `#[thread_local]` is a plain word applied to a static.
+    fn check_thread_local(&self, attr: &ast::Attribute, span: Span, target: Target) {
+        if target != Target::Static && target != Target::ForeignStatic {
+            self.emit(struct_span_err!(self.sess, attr.span, E0691,
+                                       "attribute should be applied to a static")
+                .span_label(span, format!("not {}", Target::Static.description())));
+            return;
+        }
+
+        if !attr.is_word() {
+            self.emit(&mut struct_span_err!(self.sess, attr.span, E0692,
+                                            "the `thread_local` attribute takes no arguments"));
+        }
+    }
+
+    /// Check if an `#[repr]` attr is valid.
+    fn check_repr(&mut self,
+                  attr: &ast::Attribute,
+                  span: Span,
+                  target: Target,
+                  item: Option<&ast::Item>) {
+        let hints = repr_hints(attr);
+        if hints.is_empty() {
+            return;
+        }
 
-        let mut int_reprs = 0;
         let mut is_c = false;
-        let mut is_simd = false;
+        let mut is_packed = false;
+        let mut align = None;
+        let mut align_span = None;
 
-        for word in words {
+        let is_lone_hint = hints.len() == 1;
+        for hint in &hints {
+            // Resolve the symbol once, rather than for every comparison below.
+            let interned = hint.name.as_str();
+            let name = &*interned;
 
-            let name = match word.name() {
-                Some(word) => word,
-                None => continue,
-            };
+            // Only `align` and `packed` take arguments, every other hint is a
+            // plain word.
+            if hint.args != ReprHintArgs::Word && name != "align" && name != "packed" {
+                self.emit(&mut struct_span_err!(self.sess, hint.span, E0689,
+                                                "the `{}` representation hint takes no arguments",
+                                                name));
+                continue;
+            }
 
-            let (message, label) = match &*name.as_str() {
+            let (message, expected) = match name {
                 "C" => {
                     is_c = true;
+                    // Accepted on foreign statics, whose declarations often
+                    // mirror a C header.
                     if target != Target::Struct &&
                             target != Target::Union &&
-                            target != Target::Enum {
+                            target != Target::Enum &&
+                            target != Target::ForeignStatic {
                                 ("attribute should be applied to struct, enum or union",
-                                 "a struct, enum or union")
+                                 &[Target::Struct, Target::Enum, Target::Union][..])
                     } else {
                         continue
                     }
@@ -101,28 +575,90 @@ impl<'a> CheckAttrVisitor<'a> {
                 "packed" => {
                     // Do not increment conflicting_reprs here, because "packed"
                     // can be used to modify another repr hint
+                    is_packed = hint.args == ReprHintArgs::Word ||
+                                hint.args == ReprHintArgs::Int(1);
+                    if let ReprHintArgs::Int(packing) = hint.args {
+                        let problem = if packing == 0 {
+                            Some("packing must be at least 1 byte")
+                        } else if !packing.is_power_of_two() {
+                            Some("packing must be a power of two")
+                        } else if packing > 1 {
+                            Some("packing to more than 1 byte is not supported")
+                        } else {
+                            None
+                        };
+                        if let Some(problem) = problem {
+                            let mut err = struct_span_err!(self.sess, hint.span, E0696,
+                                                           "invalid `repr(packed)` attribute: \
+                                                            `packed({})`", packing);
+                            err.span_label(hint.span, problem);
+                            // A field is never aligned to more than its size.
+                            match item.and_then(max_primitive_field_size) {
+                                Some(size) if packing > 1 && size <= packing => {
+                                    let bytes = if size == 1 { "byte" } else { "bytes" };
+                                    err.note(&format!("no field of this type is larger than \
+                                                       {} {}, so it wouldn't be packed any \
+                                                       further than it already is",
+                                                      size, bytes));
+                                }
+                                _ => {}
+                            }
+                            self.emit(&mut err);
+                        }
+                    }
                     if target != Target::Struct &&
                             target != Target::Union {
                                 ("attribute should be applied to struct or union",
-                                 "a struct or union")
+                                 &[Target::Struct, Target::Union][..])
                     } else {
                         continue
                     }
                 }
                 "simd" => {
-                    is_simd = true;
                     if target != Target::Struct {
                         ("attribute should be applied to struct",
-                         "a struct")
+                         &[Target::Struct][..])
                     } else {
                         continue
                     }
                 }
                 "align" => {
+                    if let ReprHintArgs::Int(value) = hint.args {
+                        // `find_repr_attrs` enforces the bound every target
+                        // shares, some backends support less.
+                        match self.sess.target.target.options.max_align {
+                            Some(max) if value > max as u128 => {
+                                let msg = format!("invalid `repr(align)` attribute: larger than \
+                                                   the target's maximum of {} bytes", max);
+                                self.emit(&mut self.sess.struct_span_err_with_code(
+                                    hint.span, &msg, DiagnosticId::Error("E0589".to_owned())));
+                            }
+                            _ => {}
+                        }
+                        match (align, align_span) {
+                            (Some(first), Some(first_span)) if first != value => {
+                                self.emit(struct_span_err!(self.sess, hint.span, E0693,
+                                                           "conflicting `align` representation \
+                                                            hints")
+                                    .span_label(first_span,
+                                                format!("alignment {} requested here", first))
+                                    .span_label(hint.span,
+                                                format!("alignment {} requested here", value)));
+                            }
+                            (Some(_), _) => {}
+                            (None, _) => {
+                                align = Some(value);
+                                align_span = Some(hint.span);
+                            }
+                        }
+                    }
+                    // On an enum this raises the alignment of every variant,
+                    // and so of the whole enum.
                     if target != Target::Struct &&
-                            target != Target::Union {
-                        ("attribute should be applied to struct or union",
-                         "a struct or union")
+                            target != Target::Union &&
+                            target != Target::Enum {
+                        ("attribute should be applied to struct, enum or union",
+                         &[Target::Struct, Target::Enum, Target::Union][..])
                     } else {
                         continue
                     }
@@ -130,43 +666,692 @@ impl<'a> CheckAttrVisitor<'a> {
                 "i8" | "u8" | "i16" | "u16" |
                 "i32" | "u32" | "i64" | "u64" |
                 "isize" | "usize" => {
-                    int_reprs += 1;
                     if target != Target::Enum {
                         ("attribute should be applied to enum",
-                         "an enum")
+                         &[Target::Enum][..])
                     } else {
                         continue
                     }
                 }
+                // `find_repr_attrs` reports unknown hints on the types it's
+                // used for. Move on rather than return, as the hints that
+                // follow still need checking.
                 _ => continue,
             };
-            struct_span_err!(self.sess, attr.span, E0517, "{}", message)
-                .span_label(item.span, format!("not {}", label))
-                .emit();
+            let mut err = struct_span_err!(self.sess, attr.span, E0517, "{}", message);
+            let label = match item.and_then(describe_struct) {
+                Some(actual) => format!("{}, not {}", actual, describe_targets(expected)),
+                None => format!("not {}", describe_targets(expected)),
+            };
+            err.span_label(span, label);
+            if name == "simd" && target == Target::Union {
+                err.note("SIMD types must be structs, not unions");
+            }
+            // Only the integer hints expect an enum. `C` is the closest thing
+            // a struct can have, so offer it when it's a plain swap.
+            if expected == [Target::Enum] && target == Target::Struct && is_lone_hint {
+                let style = if attr.style == ast::AttrStyle::Inner { "#!" } else { "#" };
+                err.span_suggestion(attr.span,
+                                    "use `repr(C)` to give a struct a fixed layout",
+                                    format!("{}[repr(C)]", style));
+            }
+            if target == Target::AssocTy || target == Target::AssocConst {
+                err.note("representation hints apply to type definitions, \
+                          not to associated items");
+            }
+            if target == Target::Const || target == Target::Static {
+                err.note("representation hints apply to type definitions, \
+                          not to constants or statics");
+            }
+            if target == Target::MacroDef {
+                err.note("representation hints apply to type definitions, \
+                          not to the macros that may expand to them");
+            }
+            if target == Target::TraitAlias {
+                err.note("a trait alias names a set of bounds, not a type, so it has no layout");
+            }
+            if target == Target::GlobalAsm {
+                err.note("a global assembly block is passed to the assembler as written, \
+                          so it has no layout");
+            }
+            if target == Target::Closure || target == Target::Expression {
+                err.note("representation hints apply to type definitions, not to expressions");
+            }
+            if target == Target::Arm {
+                err.note("representation hints apply to type definitions, not to match arms");
+            }
+            if target == Target::Statement {
+                // Only `let` statements are checked with this target.
+                err.note("representation hints apply to type definitions, not to `let` \
+                          bindings, the hint belongs on the definition of the bound value's type");
+            }
+            if target == Target::Other {
+                if let Some(item) = item {
+                    err.note(&format!("this item kind ({}) does not support `repr`",
+                                      item_kind_name(item)));
+                }
+            }
+            if target == Target::ImplTraitAlias {
+                err.note("the type behind `impl Trait` is opaque, so its layout is whatever \
+                          the hidden type's is and can't be chosen with hints");
+            }
+            if target == Target::Use {
+                if item.map_or(false, is_reexport) {
+                    err.note("attributes on a re-export don't change the items it \
+                              re-exports, they apply at the definition site");
+                }
+                err.note("a `use` declaration only imports names, the hints belong on the \
+                          definitions of the types it imports");
+            }
+            if target == Target::ExternCrate {
+                err.note("an `extern crate` item only links to another crate, the hints belong \
+                          on the definitions of that crate's types");
+            }
+            if target == Target::Variant {
+                err.note("the representation of an enum is set on the enum declaration, \
+                          not on its variants");
+            }
+            if target == Target::ForeignMod {
+                err.note("an extern block has no layout of its own, the hints would apply to \
+                          the type definitions its items use");
+            }
+            if target == Target::ForeignStatic {
+                err.note("the layout of a foreign static is fixed by the other library, \
+                          so only `repr(C)` is accepted on one");
+            }
+            if target == Target::TyAlias && item.map_or(false, is_fn_ptr_alias) {
+                err.note("function pointer types already carry their ABI in their type, \
+                          e.g. `extern \"C\" fn()`");
+            }
+            err.help("the representation hints are `C`, `packed`, `simd`, `align(N)` and \
+                      the integer types `i8` to `isize` and `u8` to `usize`");
+            // Layout relies on the hints of a type being valid for it, so
+            // only hints on something without a layout can be warnings.
+            if target == Target::Struct || target == Target::Enum || target == Target::Union {
+                self.emit(&mut err);
+            } else {
+                self.emit_misplaced(&mut err);
+            }
+        }
+
+        if let Some(note) = repr_conflict(&hints, item) {
+            self.report_repr_conflict(attr.span, note, &[attr]);
+        }
+
+        // People reach for `align(1)` to drop padding, but `align` can only
+        // ever raise the alignment the fields already require.
+        if !is_packed && align == Some(1) &&
+                (target == Target::Struct || target == Target::Union) {
+            if let Some(item) = item {
+                self.buffer_lint(lint::builtin::REPR_ALIGN_ONE, item.id, attr.span,
+                                 "`align(1)` can only raise the alignment of a type, \
+                                  use `packed` to lower it");
+            }
+        }
+
+        // A type with a single primitive field is already aligned like that
+        // field, so asking for the same alignment again changes nothing.
+        if let (Some(align), Some(item)) = (align, item) {
+            if !is_packed && align > 1 && self.single_primitive_field_align(item) == Some(align) {
+                let msg = format!("`align({})` has no effect, the only field of this type \
+                                   is already aligned to {} bytes", align, align);
+                self.buffer_lint(lint::builtin::REPR_ALIGN_REDUNDANT, item.id, attr.span, &msg);
+            }
+        }
+
+        if is_c && is_packed && target == Target::Struct {
+            if let Some(item) = item {
+                self.buffer_lint(lint::builtin::REPR_C_PACKED, item.id, attr.span,
+                                 "`repr(C, packed)` keeps the declared field order \
+                                  and also removes all padding between fields");
+            }
+        }
+
+        // Without `C` the compiler is still free to reorder the fields, which
+        // matters as soon as the struct is shared with C. A `C` hint in
+        // another `#[repr]` attribute counts, so only look at lone attributes.
+        if !is_c && is_packed && target == Target::Struct {
+            if let Some(item) = item {
+                if repr_attr_count(&item.attrs) == 1 {
+                    self.buffer_lint(lint::builtin::REPR_PACKED_WITHOUT_C, item.id, attr.span,
+                                     "the field order of a `repr(packed)` struct is \
+                                      unspecified, use `repr(C, packed)` for a stable layout");
+                }
+            }
+        }
+
+        // Only its own module can name a private struct, so most of the time
+        // nothing depends on its layout either. Not all such uses can be
+        // seen from here, pointers handed to C for one, hence only a lint.
+        if is_c && target == Target::Struct {
+            if let Some(item) = item {
+                if item.vis == ast::Visibility::Inherited {
+                    self.buffer_lint(lint::builtin::REPR_C_PRIVATE, item.id, attr.span,
+                                     "this `repr(C)` struct is private, `C` is only needed \
+                                      if its layout must stay stable, e.g. for FFI");
+                }
+            }
         }
 
-        // Warn on repr(u8, u16), repr(C, simd), and c-like-enum-repr(C, u8)
-        if (int_reprs > 1)
-           || (is_simd && is_c)
-           || (int_reprs == 1 && is_c && is_c_like_enum(item)) {
-            span_warn!(self.sess, attr.span, E0566,
-                       "conflicting representation hints");
+        // Lifetime parameters can't change the layout, so only type parameters
+        // make the field offsets depend on how the struct is instantiated.
+        if is_c && target == Target::Struct {
+            if let Some(item) = item {
+                if has_type_params(item) {
+                    self.buffer_lint(lint::builtin::REPR_C_GENERIC, item.id, attr.span,
+                                     "the layout of this `repr(C)` struct depends on \
+                                      the types its type parameters are instantiated with");
+                }
+            }
+        }
+
+        // The discriminant of such an enum can only ever have one value, so
+        // a struct with the variant's fields has the same use without it.
+        if is_c && target == Target::Enum {
+            if let Some(item) = item {
+                if is_single_data_variant_enum(item) {
+                    self.buffer_lint(lint::builtin::REPR_C_SINGLE_VARIANT, item.id, attr.span,
+                                     "this `repr(C)` enum has a single variant with data, \
+                                      a `repr(C)` struct may be more appropriate");
+                }
+            }
+        }
+
+        // Both are fine together, but they pull in different directions: the
+        // layout is fixed for C, while other crates must allow for variants
+        // that don't exist yet, and C code has no such obligation.
+        if is_c && target == Target::Enum {
+            if let Some(item) = item {
+                if attr::contains_name(&item.attrs, "non_exhaustive") {
+                    self.buffer_lint(lint::builtin::REPR_C_NON_EXHAUSTIVE, item.id, attr.span,
+                                     "this `repr(C)` enum is also `#[non_exhaustive]`, so \
+                                      other crates must expect variants it doesn't have yet \
+                                      even though its layout is fixed");
+                }
+            }
+        }
+
+        // Unions shared with C are usually plain data, and derives are
+        // already expanded here, leaving only the marker `derive(Copy)` adds.
+        if is_c && target == Target::Union {
+            if let Some(item) = item {
+                if !attr::contains_name(&item.attrs, "rustc_copy_clone_marker") {
+                    self.buffer_lint(lint::builtin::REPR_C_UNION_NOT_COPY, item.id, attr.span,
+                                     "this `repr(C)` union doesn't derive `Copy`");
+                }
+            }
         }
     }
+
+    /// Check for representation hints that only conflict once the hints of
+    /// all of an item's `#[repr]` attributes are put together, as in
+    /// `#[repr(u8)]` followed by `#[repr(u16)]`. Conflicts within a single
+    /// attribute are left to `check_repr`.
+    fn check_repr_across_attrs(&mut self, item: &ast::Item) {
+        let attrs = repr_attrs(&item.attrs);
+        if attrs.len() < 2 {
+            return;
+        }
+        let last = attrs[attrs.len() - 1];
+        let mut hints = Vec::new();
+        let mut c_and_packed_in_one = false;
+        for attr in &attrs {
+            let attr_hints = repr_hints(attr);
+            if repr_conflict(&attr_hints, Some(item)).is_some() {
+                return;
+            }
+            c_and_packed_in_one |= has_c_and_packed(&attr_hints);
+            hints.extend(attr_hints);
+        }
+        if let Some(note) = repr_conflict(&hints, Some(item)) {
+            self.report_repr_conflict(last.span, note, &attrs);
+        }
+
+        if !c_and_packed_in_one && has_c_and_packed(&hints) &&
+                Target::from_item(item) == Target::Struct {
+            let mut span = MultiSpan::from_span(last.span);
+            for attr in attrs.iter().filter(|attr| self.is_from_cfg_attr(attr)) {
+                span.push_span_label(attr.span, "applied by a `cfg_attr`".to_string());
+            }
+            self.buffer_lint(lint::builtin::REPR_C_PACKED, item.id, span,
+                             "`repr(C, packed)` keeps the declared field order \
+                              and also removes all padding between fields");
+        }
+    }
+
+    /// Warn about conflicting representation hints at `span`, or hold the
+    /// warning back for `report_repr_conflicts`. `attrs` are the attributes
+    /// the hints were gathered from.
+    fn report_repr_conflict(&mut self, span: Span, note: &str, attrs: &[&ast::Attribute]) {
+        if self.sess.opts.debugging_opts.repr_conflict_summary.is_some() {
+            self.repr_conflicts.push(span);
+            return;
+        }
+        let mut err = self.struct_repr_conflict(span, "conflicting representation hints");
+        err.note(note);
+        for attr in attrs.iter().filter(|attr| self.is_from_cfg_attr(attr)) {
+            err.span_label(attr.span, "applied by a `cfg_attr`");
+        }
+        self.emit(&mut err);
+    }
+
+    /// Whether `attr` was written inside a `#[cfg_attr]`. Expanding one
+    /// keeps the span of the inner attribute, which has no `#[` of its own.
+    fn is_from_cfg_attr(&self, attr: &ast::Attribute) -> bool {
+        if attr.is_sugared_doc {
+            return false;
+        }
+        match self.sess.codemap().span_to_snippet(attr.span) {
+            Ok(snippet) => !snippet.starts_with('#'),
+            Err(_) => false,
+        }
+    }
+
+    /// Start an E0566 diagnostic, which is a warning unless
+    /// `-Z deny-repr-conflicts` makes it an error.
+    fn struct_repr_conflict(&self, span: Span, msg: &str) -> DiagnosticBuilder<'a> {
+        if self.sess.opts.debugging_opts.deny_repr_conflicts {
+            struct_span_err!(self.sess, span, E0566, "{}", msg)
+        } else {
+            struct_span_warn!(self.sess, span, E0566, "{}", msg)
+        }
+    }
+
+    /// Report the conflicting representation hints held back by
+    /// `-Z repr-conflict-summary`: individually while there are no more than
+    /// the threshold, and as a single warning once there are more.
+    fn report_repr_conflicts(&self) {
+        let threshold = match self.sess.opts.debugging_opts.repr_conflict_summary {
+            Some(threshold) => threshold,
+            None => return,
+        };
+        if self.repr_conflicts.len() <= threshold {
+            for &span in &self.repr_conflicts {
+                self.emit(&mut self.struct_repr_conflict(span,
+                                                         "conflicting representation hints"));
+            }
+        } else {
+            let mut err = self.struct_repr_conflict(self.repr_conflicts[0],
+                                                    &format!("{} items have conflicting \
+                                                              representation hints",
+                                                             self.repr_conflicts.len()));
+            err.note(&format!("individual warnings are suppressed above {} by \
+                               `-Z repr-conflict-summary`", threshold));
+            self.emit(&mut err);
+        }
+        self.emit_pending();
+    }
 }
 
 impl<'a> Visitor<'a> for CheckAttrVisitor<'a> {
     fn visit_item(&mut self, item: &'a ast::Item) {
+        // An unexpanded macro invocation is only seen by callers such as
+        // `check_item` running before expansion, and its attributes are
+        // checked on whatever it expands to, if they're kept at all.
+        if let ast::ItemKind::Mac(..) = item.node {
+            return;
+        }
         let target = Target::from_item(item);
         for attr in &item.attrs {
-            self.check_attribute(attr, item, target);
+            self.check_attribute(attr, item.span, target, Some(item));
+        }
+        self.check_repr_across_attrs(item);
+        self.check_cold_inline(item.id, &item.attrs);
+        self.check_inline_target_feature(item.id, &item.attrs);
+        if target == Target::Static {
+            self.check_no_mangle_thread_local(item.id, &item.attrs);
         }
+        if target == Target::Fn {
+            self.check_inline_no_mangle(item);
+        }
+        if let ast::ItemKind::Fn(.., ref body) = item.node {
+            self.check_inline_empty_fn(item.id, &item.attrs, body);
+        }
+        self.emit_pending();
         visit::walk_item(self, item);
     }
+
+    fn visit_foreign_item(&mut self, item: &'a ast::ForeignItem) {
+        let target = Target::from_foreign_item(item);
+        for attr in &item.attrs {
+            self.check_attribute(attr, item.span, target, None);
+        }
+        self.emit_pending();
+        visit::walk_foreign_item(self, item);
+    }
+
+    fn visit_trait_item(&mut self, item: &'a ast::TraitItem) {
+        let target = Target::from_trait_item(item);
+        for attr in &item.attrs {
+            self.check_attribute(attr, item.span, target, None);
+        }
+        self.check_cold_inline(item.id, &item.attrs);
+        self.check_inline_target_feature(item.id, &item.attrs);
+        self.check_required_method_inline(item);
+        if let ast::TraitItemKind::Method(ref sig, ref default) = item.node {
+            self.check_must_use_unit(item.id, &item.attrs, sig);
+            if let Some(ref body) = *default {
+                self.check_inline_empty_fn(item.id, &item.attrs, body);
+            }
+        }
+        self.emit_pending();
+        visit::walk_trait_item(self, item);
+    }
+
+    fn visit_impl_item(&mut self, item: &'a ast::ImplItem) {
+        let target = Target::from_impl_item(item);
+        for attr in &item.attrs {
+            self.check_attribute(attr, item.span, target, None);
+        }
+        self.check_cold_inline(item.id, &item.attrs);
+        self.check_inline_target_feature(item.id, &item.attrs);
+        if let ast::ImplItemKind::Method(ref sig, ref body) = item.node {
+            self.check_must_use_unit(item.id, &item.attrs, sig);
+            self.check_inline_empty_fn(item.id, &item.attrs, body);
+        }
+        self.emit_pending();
+        visit::walk_impl_item(self, item);
+    }
+
+    fn visit_expr(&mut self, expr: &'a ast::Expr) {
+        let target = match expr.node {
+            ast::ExprKind::Closure(..) => Target::Closure,
+            _ => Target::Expression,
+        };
+        for attr in expr.attrs.iter() {
+            self.check_attribute(attr, expr.span, target, None);
+        }
+        self.emit_pending();
+        visit::walk_expr(self, expr);
+    }
+
+    fn visit_stmt(&mut self, stmt: &'a ast::Stmt) {
+        // Attributes on expression statements are the expression's, and
+        // items are visited on their own.
+        if let ast::StmtKind::Local(ref local) = stmt.node {
+            for attr in local.attrs.iter() {
+                self.check_attribute(attr, local.span, Target::Statement, None);
+            }
+        }
+        self.emit_pending();
+        visit::walk_stmt(self, stmt);
+    }
+
+    fn visit_arm(&mut self, arm: &'a ast::Arm) {
+        let span = arm.pats[0].span.to(arm.body.span);
+        for attr in &arm.attrs {
+            self.check_attribute(attr, span, Target::Arm, None);
+        }
+        self.emit_pending();
+        visit::walk_arm(self, arm);
+    }
+
+    fn visit_variant(&mut self,
+                     variant: &'a ast::Variant,
+                     generics: &'a ast::Generics,
+                     item_id: ast::NodeId) {
+        for attr in &variant.node.attrs {
+            self.check_attribute(attr, variant.span, Target::Variant, None);
+        }
+        self.emit_pending();
+        visit::walk_variant(self, variant, generics, item_id);
+    }
+
+    fn visit_generic_param(&mut self, param: &'a ast::GenericParam) {
+        let (attrs, span) = match *param {
+            ast::GenericParam::Lifetime(ref def) => (&def.attrs, def.lifetime.span),
+            ast::GenericParam::Type(ref ty) => (&ty.attrs, ty.span),
+        };
+        for attr in attrs.iter() {
+            self.check_attribute(attr, span, Target::GenericParam, None);
+        }
+        self.emit_pending();
+        visit::walk_generic_param(self, param);
+    }
+
+    fn visit_mac(&mut self, _mac: &'a ast::Mac) {
+        // Macros are expanded before attributes are checked, see `visit_item`.
+    }
+}
+
+/// The outcome of checking a single attribute.
+#[derive(Clone, Debug)]
+pub struct AttrValidation {
+    pub span: Span,
+    /// The errors the attribute would cause, empty if it is valid.
+    pub errors: Vec<String>,
+}
+
+impl AttrValidation {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Check the attributes of `item` as `check_crate` would, but return the
+/// outcome for each attribute instead of emitting anything. Warnings and
+/// lints are not reported.
+pub fn validate_item_attrs(sess: &Session, item: &ast::Item) -> Vec<AttrValidation> {
+    item.attrs.iter().map(|attr| {
+        let errors = check_single_attribute(sess, attr, item).into_iter()
+            .filter(|diagnostic| diagnostic.level == Level::Error)
+            .map(|diagnostic| diagnostic.message())
+            .collect();
+        AttrValidation {
+            span: attr.span,
+            errors: errors,
+        }
+    }).collect()
+}
+
+/// Run `validate_item_attrs` on every item of `krate`, nested ones included,
+/// and return the outcomes by item.
+///
+/// The outcomes are keyed by node id, so `krate` must already be expanded.
+/// Before expansion every item is `DUMMY_NODE_ID` and they would all end up
+/// in one entry.
+///
+/// `check_crate` doesn't report from this map. It only holds the errors of
+/// item attributes, while `check_crate` also checks the attributes of
+/// expressions, statements and other non-item nodes, reports warnings and
+/// checks the hints of an item's attributes against each other.
+pub fn validate_crate_attrs(sess: &Session, krate: &ast::Crate) -> NodeMap<Vec<AttrValidation>> {
+    struct ItemValidator<'a> {
+        sess: &'a Session,
+        results: NodeMap<Vec<AttrValidation>>,
+    }
+
+    impl<'a> Visitor<'a> for ItemValidator<'a> {
+        fn visit_item(&mut self, item: &'a ast::Item) {
+            self.results.insert(item.id, validate_item_attrs(self.sess, item));
+            visit::walk_item(self, item);
+        }
+
+        fn visit_mac(&mut self, _mac: &'a ast::Mac) {
+            // Macros are expanded before attributes are checked.
+        }
+    }
+
+    let mut validator = ItemValidator {
+        sess: sess,
+        results: NodeMap(),
+    };
+    visit::walk_crate(&mut validator, krate);
+    validator.results
 }
 
+/// Check `attr`, one of the attributes of `item`, as `check_crate` would and
+/// return the diagnostics it causes instead of emitting them. Lints, which
+/// only `check_crate_lints` reports, are not included.
+pub fn check_single_attribute(sess: &Session,
+                              attr: &ast::Attribute,
+                              item: &ast::Item) -> Vec<Diagnostic> {
+    if let ast::ItemKind::Mac(..) = item.node {
+        return Vec::new();
+    }
+    let mut visitor = CheckAttrVisitor::new(sess, Mode::Collect);
+    visitor.check_attribute(attr, item.span, Target::from_item(item), Some(item));
+    visitor.collected.into_inner()
+}
+
+/// Check the attributes of `item` and everything nested in it, as
+/// `check_crate` does for each item of the crate. Meant for re-checking a
+/// single item, e.g. one being edited. Like `check_crate` it drops lints, so
+/// it can also run after the early lint checks have taken the lint buffer.
+pub fn check_item(sess: &Session, item: &ast::Item) {
+    let mut visitor = CheckAttrVisitor::new(sess, Mode::Emit);
+    visitor.visit_item(item);
+    visitor.report_repr_conflicts();
+}
+
+/// Check the attributes of `krate` and emit the errors and warnings found.
+/// The lints are reported by `check_crate_lints` instead.
 pub fn check_crate(sess: &Session, krate: &ast::Crate) {
-    visit::walk_crate(&mut CheckAttrVisitor { sess: sess }, krate);
+    check_crate_attrs(sess, krate, Mode::Emit, None);
+}
+
+/// Check the attributes of `krate` and buffer the lints found for the early
+/// lint checks, which means this has to run before them. Errors and warnings
+/// are left to `check_crate`.
+pub fn check_crate_lints(sess: &Session, krate: &ast::Crate) {
+    check_crate_attrs(sess, krate, Mode::Lint, None);
+}
+
+/// How often each attribute appears in a crate and on what kinds of nodes,
+/// as tallied by `check_crate_with_usage`.
+#[derive(Clone, Debug, Default)]
+pub struct AttrUsage {
+    counts: FxHashMap<(Symbol, Target), usize>,
+}
+
+impl AttrUsage {
+    /// The number of times the attribute `name` appears.
+    pub fn count(&self, name: &str) -> usize {
+        self.counts.iter()
+            .filter(|&(&(attr, _), _)| attr == name)
+            .map(|(_, &count)| count)
+            .sum()
+    }
+
+    /// The number of times the attribute `name` appears on `target`.
+    pub fn count_on(&self, name: &str, target: Target) -> usize {
+        self.counts.iter()
+            .filter(|&(&(attr, on), _)| attr == name && on == target)
+            .map(|(_, &count)| count)
+            .sum()
+    }
+
+    /// The attributes used and what they were used on, with their counts.
+    pub fn iter<'a>(&'a self) -> Box<Iterator<Item = (Symbol, Target, usize)> + 'a> {
+        Box::new(self.counts.iter().map(|(&(attr, target), &count)| (attr, target, count)))
+    }
+}
+
+/// Tally how often each attribute of `krate` is used and on what. Meant for
+/// crate audits. The attributes are checked as `check_crate` does, but
+/// nothing is emitted or buffered, so this can run at any point, alongside
+/// the normal checks or without them.
+pub fn check_crate_with_usage(sess: &Session, krate: &ast::Crate) -> AttrUsage {
+    check_crate_attrs(sess, krate, Mode::Collect, Some(AttrUsage::default())).unwrap_or_default()
+}
+
+fn check_crate_attrs(sess: &Session,
+                     krate: &ast::Crate,
+                     mode: Mode,
+                     usage: Option<AttrUsage>) -> Option<AttrUsage> {
+    let mut visitor = CheckAttrVisitor::new(sess, mode);
+    visitor.usage = usage;
+    // The same as `check_item` on each item, but with one visitor so that
+    // `-Z repr-conflict-summary` counts the whole crate.
+    for item in &krate.module.items {
+        visitor.visit_item(item);
+    }
+    visitor.report_repr_conflicts();
+    visitor.usage
+}
+
+/// The arguments of `attr` if it has the list form, e.g. `#[repr(C)]`.
+///
+/// Getting them means parsing the attribute's tokens, so word attributes such
+/// as `#[inline]`, which have no tokens, are ruled out first.
+fn meta_item_list(attr: &ast::Attribute) -> Option<Vec<ast::NestedMetaItem>> {
+    if attr.is_word() {
+        return None;
+    }
+    attr.meta_item_list()
+}
+
+/// Whether a function declared with `decl` returns `()`, either implicitly
+/// or by writing it out.
+fn returns_unit(decl: &ast::FnDecl) -> bool {
+    match decl.output {
+        ast::FunctionRetTy::Default(_) => true,
+        ast::FunctionRetTy::Ty(ref ty) => match ty.node {
+            ast::TyKind::Tup(ref tys) => tys.is_empty(),
+            _ => false,
+        },
+    }
+}
+
+/// Whether `attr` is exactly `#[inline(always)]`.
+fn is_inline_always(attr: &ast::Attribute) -> bool {
+    match meta_item_list(attr) {
+        Some(ref list) if list.len() == 1 => list[0].check_name("always") && list[0].is_word(),
+        _ => false,
+    }
+}
+
+/// Looks for a call to the function `name` by its plain name, as in `f()`.
+struct SelfCallFinder {
+    name: ast::Name,
+    found: bool,
+    /// Whether the function declares its own item or binding called `name`,
+    /// in which case a call may not be to the outer function at all.
+    shadowed: bool,
+}
+
+impl<'a> Visitor<'a> for SelfCallFinder {
+    fn visit_expr(&mut self, expr: &'a ast::Expr) {
+        if let ast::ExprKind::Call(ref callee, _) = expr.node {
+            if let ast::ExprKind::Path(None, ref path) = callee.node {
+                if path.segments.len() == 1 && path.segments[0].identifier.name == self.name {
+                    self.found = true;
+                }
+            }
+        }
+        visit::walk_expr(self, expr);
+    }
+
+    // Calls inside nested items aren't calls from this function.
+    fn visit_item(&mut self, item: &'a ast::Item) {
+        if item.ident.name == self.name {
+            self.shadowed = true;
+        }
+    }
+
+    // Covers parameters and `let`s, closure parameters included.
+    fn visit_pat(&mut self, pat: &'a ast::Pat) {
+        if let ast::PatKind::Ident(_, ref ident, _) = pat.node {
+            if ident.node.name == self.name {
+                self.shadowed = true;
+            }
+        }
+        visit::walk_pat(self, pat);
+    }
+
+    fn visit_mac(&mut self, _mac: &'a ast::Mac) {
+        // Unexpanded macros can't be looked into, so any call they make is missed.
+    }
+}
+
+/// Whether `item` is an enum with exactly one variant, which has fields.
+fn is_single_data_variant_enum(item: &ast::Item) -> bool {
+    match item.node {
+        ast::ItemKind::Enum(ref def, _) => {
+            def.variants.len() == 1 && !def.variants[0].node.data.fields().is_empty()
+        }
+        _ => false,
+    }
 }
 
 fn is_c_like_enum(item: &ast::Item) -> bool {
@@ -182,3 +1367,509 @@ fn is_c_like_enum(item: &ast::Item) -> bool {
         false
     }
 }
+
+/// Whether `item` is a type alias for a function pointer type.
+fn is_fn_ptr_alias(item: &ast::Item) -> bool {
+    match item.node {
+        ast::ItemKind::Ty(ref ty, _) => match ty.node {
+            ast::TyKind::BareFn(..) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Whether a struct item has any type parameters, ignoring lifetimes.
+fn has_type_params(item: &ast::Item) -> bool {
+    match item.node {
+        ast::ItemKind::Struct(_, ref generics) => generics.is_type_parameterized(),
+        _ => false,
+    }
+}
+
+/// Describe a struct item by its kind, e.g. "a tuple struct".
+fn describe_struct(item: &ast::Item) -> Option<&'static str> {
+    match item.node {
+        ast::ItemKind::Struct(ast::VariantData::Struct(..), _) => Some("a struct"),
+        ast::ItemKind::Struct(ast::VariantData::Tuple(..), _) => Some("a tuple struct"),
+        ast::ItemKind::Struct(ast::VariantData::Unit(..), _) => Some("a unit struct"),
+        _ => None,
+    }
+}
+
+/// What kind of item `item` is, as written, e.g. "module".
+fn item_kind_name(item: &ast::Item) -> &str {
+    match item.node {
+        ast::ItemKind::Impl(..) => "impl block",
+        ast::ItemKind::AutoImpl(..) => "auto trait impl",
+        ast::ItemKind::Mac(..) => "macro invocation",
+        ref node => node.descriptive_variant(),
+    }
+}
+
+/// The number of `#[repr]` attributes in `attrs`.
+fn repr_attr_count(attrs: &[ast::Attribute]) -> usize {
+    repr_attrs(attrs).len()
+}
+
+/// The `#[repr]` attributes among `attrs`. Doc comments are attributes too and
+/// may sit between them, so they're skipped rather than treated as a hint.
+fn repr_attrs(attrs: &[ast::Attribute]) -> Vec<&ast::Attribute> {
+    attrs.iter().filter(|attr| !attr.is_sugared_doc && attr.check_name("repr")).collect()
+}
+
+/// Whether `item` is a `use` declaration that re-exports what it imports.
+fn is_reexport(item: &ast::Item) -> bool {
+    match item.node {
+        ast::ItemKind::Use(..) => item.vis != ast::Visibility::Inherited,
+        _ => false,
+    }
+}
+
+/// Whether `hints` include both `C` and `packed`.
+fn has_c_and_packed(hints: &[ReprHint]) -> bool {
+    let has = |name: &str, args: &[ReprHintArgs]| {
+        hints.iter().any(|hint| hint.name == name && args.contains(&hint.args))
+    };
+    has("C", &[ReprHintArgs::Word]) &&
+        has("packed", &[ReprHintArgs::Word, ReprHintArgs::Int(1)])
+}
+
+/// Why `hints` conflict, if they do: `repr(u8, u16)`, `repr(C, simd)`, and
+/// `repr(C, u8)` on a C-like enum.
+fn repr_conflict(hints: &[ReprHint], item: Option<&ast::Item>) -> Option<&'static str> {
+    let mut int_reprs = 0;
+    let mut is_c = false;
+    let mut is_simd = false;
+    for hint in hints.iter().filter(|hint| hint.args == ReprHintArgs::Word) {
+        match &*hint.name.as_str() {
+            "C" => is_c = true,
+            "simd" => is_simd = true,
+            "i8" | "u8" | "i16" | "u16" |
+            "i32" | "u32" | "i64" | "u64" |
+            "isize" | "usize" => int_reprs += 1,
+            _ => {}
+        }
+    }
+    if int_reprs > 1 {
+        Some("only one integer type can be used for the discriminant")
+    } else if is_simd && is_c {
+        Some("`simd` already determines the layout, so it can't be combined with `C`")
+    } else if int_reprs == 1 && is_c && item.map_or(false, is_c_like_enum) {
+        Some("on an enum without fields, `C` and an integer type both set the size of \
+              the discriminant")
+    } else {
+        None
+    }
+}
+
+/// The arguments given to a single representation hint.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ReprHintArgs {
+    /// A plain word, as in `C`.
+    Word,
+    /// An integer, as in `align(8)` or the older `align = "8"`.
+    Int(u128),
+    /// Anything else, as in `C(x)` or `align = "eight"`.
+    Other,
+}
+
+/// A single hint inside a `#[repr]` attribute, e.g. the `align(8)` in
+/// `#[repr(C, align(8))]`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ReprHint {
+    pub name: Symbol,
+    pub span: Span,
+    pub args: ReprHintArgs,
+}
+
+/// The hints of a `#[repr]` attribute, in the order they were written.
+///
+/// A trailing comma doesn't produce an entry at all, and literals such as
+/// `repr("C")` are skipped, they are reported by `attr::find_repr_attrs`.
+pub fn repr_hints(attr: &ast::Attribute) -> Vec<ReprHint> {
+    let words = meta_item_list(attr).unwrap_or_default();
+    words.iter().filter_map(|word| {
+        word.name().map(|name| ReprHint {
+            name: name,
+            span: word.span,
+            args: repr_hint_args(word),
+        })
+    }).collect()
+}
+
+fn repr_hint_args(word: &ast::NestedMetaItem) -> ReprHintArgs {
+    if word.is_word() {
+        return ReprHintArgs::Word;
+    }
+    if let Some((_, lit)) = word.name_value_literal() {
+        if let ast::LitKind::Int(value, ast::LitIntType::Unsuffixed) = lit.node {
+            return ReprHintArgs::Int(value);
+        }
+    }
+    match word.value_str().and_then(|value| value.as_str().parse().ok()) {
+        Some(value) => ReprHintArgs::Int(value),
+        None => ReprHintArgs::Other,
+    }
+}
+
+/// The size of the largest field of a struct or union whose fields are all
+/// primitives of a fixed size, e.g. `u8` or `f64`.
+fn max_primitive_field_size(item: &ast::Item) -> Option<u128> {
+    let fields = match item.node {
+        ast::ItemKind::Struct(ref data, _) |
+        ast::ItemKind::Union(ref data, _) => data.fields(),
+        _ => return None,
+    };
+    let mut max = None;
+    for field in fields {
+        let size = match primitive_size(&field.ty) {
+            Some(size) => size,
+            None => return None,
+        };
+        max = Some(cmp::max(max.unwrap_or(0), size));
+    }
+    max
+}
+
+/// The name of the type `ty` if it is a single identifier, as primitives are.
+fn primitive_name(ty: &ast::Ty) -> Option<ast::Name> {
+    let path = match ty.node {
+        ast::TyKind::Paren(ref ty) => return primitive_name(ty),
+        ast::TyKind::Path(None, ref path) => path,
+        _ => return None,
+    };
+    if path.segments.len() != 1 || path.segments[0].parameters.is_some() {
+        return None;
+    }
+    Some(path.segments[0].identifier.name)
+}
+
+/// The size of `ty` if it syntactically names a primitive with the same
+/// size on every target.
+fn primitive_size(ty: &ast::Ty) -> Option<u128> {
+    let name = match primitive_name(ty) {
+        Some(name) => name,
+        None => return None,
+    };
+    match &*name.as_str() {
+        "bool" | "i8" | "u8" => Some(1),
+        "i16" | "u16" => Some(2),
+        "char" | "f32" | "i32" | "u32" => Some(4),
+        "f64" | "i64" | "u64" => Some(8),
+        "i128" | "u128" => Some(16),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CheckAttrVisitor, Mode, Target, ReprHintArgs, check_crate_lints,
+                check_crate_with_usage, check_item, check_single_attribute,
+                checked_attribute_names, describe_struct, describe_targets, has_type_params,
+                meta_item_list, repr_hints, validate_crate_attrs, validate_item_attrs};
+    use errors;
+    use session::{build_session, config, Session};
+    use syntax::ast;
+    use syntax::codemap::FilePathMapping;
+    use syntax::parse::{self, ParseSess};
+    use syntax::ptr::P;
+    use syntax::visit::Visitor;
+    use syntax_pos::FileName;
+    use test::Bencher;
+
+    fn test_session() -> Session {
+        build_session(config::basic_options(), None, errors::registry::Registry::new(&[]))
+    }
+
+    fn parse_item(source: &str) -> P<ast::Item> {
+        let sess = ParseSess::new(FilePathMapping::empty());
+        let name = FileName::Custom("check_attr_test".to_string());
+        match parse::parse_item_from_source_str(name, source.to_string(), &sess) {
+            Ok(Some(item)) => item,
+            Ok(None) => panic!("no item in `{}`", source),
+            Err(mut err) => {
+                err.emit();
+                panic!("failed to parse `{}`", source)
+            }
+        }
+    }
+
+    /// Check the items in `source` as `check_crate` would, and panic if that
+    /// produces any diagnostic.
+    fn assert_no_attr_errors(source: &str) {
+        let sess = test_session();
+        let name = FileName::Custom("check_attr_test".to_string());
+        let krate = parse::parse_crate_from_source_str(name, source.to_string(), &sess.parse_sess)
+            .unwrap();
+        let mut visitor = CheckAttrVisitor::new(&sess, Mode::Collect);
+        for item in &krate.module.items {
+            visitor.visit_item(item);
+        }
+        let messages: Vec<_> = visitor.collected.into_inner()
+            .iter()
+            .map(|diagnostic| diagnostic.message())
+            .collect();
+        assert!(messages.is_empty(), "`{}` produced {:?}", source, messages);
+        assert_eq!(sess.err_count(), 0);
+    }
+
+    #[test]
+    fn test_target_description() {
+        assert_eq!(Target::Fn.description(), "a function");
+        assert_eq!(Target::Struct.description(), "a struct");
+        assert_eq!(Target::Union.description(), "a union");
+        assert_eq!(Target::Enum.description(), "an enum");
+        assert_eq!(Target::Const.description(), "a constant");
+        assert_eq!(Target::Static.description(), "a static");
+        assert_eq!(Target::TyAlias.description(), "a type alias");
+        assert_eq!(Target::ImplTraitAlias.description(), "an `impl Trait` type alias");
+        assert_eq!(Target::MacroDef.description(), "a macro definition");
+        assert_eq!(Target::TraitAlias.description(), "a trait alias");
+        assert_eq!(Target::GlobalAsm.description(), "a global assembly block");
+        assert_eq!(Target::ForeignMod.description(), "an extern block");
+        assert_eq!(Target::Use.description(), "a `use` declaration");
+        assert_eq!(Target::ExternCrate.description(), "an `extern crate` item");
+        assert_eq!(Target::Variant.description(), "an enum variant");
+        assert_eq!(Target::Closure.description(), "a closure");
+        assert_eq!(Target::Expression.description(), "an expression");
+        assert_eq!(Target::Arm.description(), "a match arm");
+        assert_eq!(Target::Statement.description(), "a statement");
+        assert_eq!(Target::GenericParam.description(), "a generic parameter");
+        assert_eq!(Target::Method.description(), "a method");
+        assert_eq!(Target::AssocConst.description(), "an associated constant");
+        assert_eq!(Target::AssocTy.description(), "an associated type");
+        assert_eq!(Target::ForeignFn.description(), "a foreign function");
+        assert_eq!(Target::ForeignStatic.description(), "a foreign static");
+        assert_eq!(Target::Other.description(), "an item");
+    }
+
+    #[test]
+    fn test_describe_targets() {
+        assert_eq!(describe_targets(&[Target::Struct]), "a struct");
+        assert_eq!(describe_targets(&[Target::Struct, Target::Union]),
+                   "a struct or a union");
+        assert_eq!(describe_targets(&[Target::Struct, Target::Enum, Target::Union]),
+                   "a struct, an enum or a union");
+    }
+
+    #[test]
+    fn test_from_foreign_item() {
+        let item = parse_item("extern { fn f(); static S: u8; type T; }");
+        let targets: Vec<_> = match item.node {
+            ast::ItemKind::ForeignMod(ref m) => {
+                m.items.iter().map(Target::from_foreign_item).collect()
+            }
+            _ => panic!("expected an extern block"),
+        };
+        assert!(targets == [Target::ForeignFn, Target::ForeignStatic, Target::Other]);
+    }
+
+    #[test]
+    fn test_has_type_params() {
+        assert!(has_type_params(&parse_item("struct S<T>(T);")));
+        assert!(has_type_params(&parse_item("struct S<'a, T>(&'a T);")));
+        assert!(!has_type_params(&parse_item("struct S<'a>(&'a u8);")));
+        assert!(!has_type_params(&parse_item("struct S(u8);")));
+    }
+
+    #[test]
+    fn test_checked_attribute_names() {
+        let names = checked_attribute_names();
+        assert!(names.contains(&"inline"));
+        assert!(names.contains(&"repr"));
+        assert!(names.contains(&"thread_local"));
+        assert!(names.contains(&"cold"));
+        assert!(names.contains(&"main"));
+        assert!(names.contains(&"lang"));
+    }
+
+    #[test]
+    fn test_repr_hints() {
+        let item = parse_item("#[repr(C, align(8))] struct S;");
+        let hints = repr_hints(&item.attrs[0]);
+        assert_eq!(hints.len(), 2);
+        assert_eq!(hints[0].name, "C");
+        assert_eq!(hints[0].args, ReprHintArgs::Word);
+        assert_eq!(hints[1].name, "align");
+        assert_eq!(hints[1].args, ReprHintArgs::Int(8));
+        assert!(hints[0].span.hi() < hints[1].span.lo());
+    }
+
+    #[test]
+    fn test_describe_struct() {
+        assert_eq!(describe_struct(&parse_item("struct S { a: u8 }")), Some("a struct"));
+        assert_eq!(describe_struct(&parse_item("struct S(u8);")), Some("a tuple struct"));
+        assert_eq!(describe_struct(&parse_item("struct S;")), Some("a unit struct"));
+        assert_eq!(describe_struct(&parse_item("union U { a: u8 }")), None);
+    }
+
+    #[test]
+    fn test_validate_item_attrs() {
+        let sess = test_session();
+        let item = parse_item("#[inline] #[repr(C)] fn f() {}");
+        let results = validate_item_attrs(&sess, &item);
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_valid());
+        assert!(!results[1].is_valid());
+        assert_eq!(results[1].errors, ["attribute should be applied to struct, enum or union"]);
+        assert_eq!(sess.err_count(), 0);
+    }
+
+    #[test]
+    fn test_check_single_attribute() {
+        let sess = test_session();
+        let item = parse_item("#[repr(u8)] fn f() {}");
+        let diagnostics = check_single_attribute(&sess, &item.attrs[0], &item);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].level, errors::Level::Error);
+        assert_eq!(diagnostics[0].code,
+                   Some(errors::DiagnosticId::Error("E0517".to_string())));
+        assert_eq!(diagnostics[0].message(), "attribute should be applied to enum");
+        assert_eq!(sess.err_count(), 0);
+    }
+
+    #[test]
+    fn test_validate_crate_attrs() {
+        let sess = test_session();
+        let name = FileName::Custom("check_attr_test".to_string());
+        let source = "#[inline] fn f() {} #[repr(C)] fn g() {}".to_string();
+        let mut krate = parse::parse_crate_from_source_str(name, source, &sess.parse_sess)
+            .unwrap();
+        // Node ids are only assigned during expansion, which needs more of a
+        // session than the test has, so number the items as it would.
+        krate.module.items = krate.module.items.into_iter().enumerate().map(|(i, item)| {
+            item.map(|mut item| {
+                item.id = ast::NodeId::new(i + 1);
+                item
+            })
+        }).collect();
+
+        let results = validate_crate_attrs(&sess, &krate);
+        assert_eq!(results.len(), 2);
+        assert!(results[&ast::NodeId::new(1)][0].is_valid());
+        assert_eq!(results[&ast::NodeId::new(2)][0].errors,
+                   ["attribute should be applied to struct, enum or union"]);
+        assert_eq!(sess.err_count(), 0);
+    }
+
+    #[test]
+    fn test_check_crate_with_usage() {
+        let sess = test_session();
+        let name = FileName::Custom("check_attr_test".to_string());
+        let source = "#[inline] fn f() {} \
+                      #[repr(C)] struct S; \
+                      impl S { #[inline] fn g() {} #[inline(never)] fn h() {} } \
+                      #[repr(u8)] #[cold] #[inline(always)] fn i() {}".to_string();
+        let krate = parse::parse_crate_from_source_str(name, source, &sess.parse_sess).unwrap();
+
+        // Neither the misplaced `repr` nor the `cold_inline_always` lint on
+        // `i` may be reported, even once the early lint checks are done.
+        sess.buffered_lints.borrow_mut().take();
+        let usage = check_crate_with_usage(&sess, &krate);
+        assert_eq!(usage.count("inline"), 4);
+        assert_eq!(usage.count_on("inline", Target::Fn), 2);
+        assert_eq!(usage.count_on("inline", Target::Method), 2);
+        assert_eq!(usage.count("repr"), 2);
+        assert_eq!(usage.count_on("repr", Target::Struct), 1);
+        assert_eq!(usage.count("cold"), 1);
+        assert_eq!(usage.iter().count(), 5);
+        assert_eq!(sess.err_count(), 0);
+    }
+
+    #[test]
+    fn test_check_crate_lints() {
+        let sess = test_session();
+        let name = FileName::Custom("check_attr_test".to_string());
+        let source = "#[repr(C)] fn f() {} #[cold] #[inline(always)] fn g() {}".to_string();
+        let krate = parse::parse_crate_from_source_str(name, source, &sess.parse_sess).unwrap();
+
+        check_crate_lints(&sess, &krate);
+        assert_eq!(sess.err_count(), 0);
+        let buffered = sess.buffered_lints.borrow();
+        assert!(buffered.as_ref().and_then(|buffer| buffer.get_any()).is_some());
+    }
+
+    #[test]
+    fn test_valid_attributes_have_no_errors() {
+        assert_no_attr_errors("#[inline] fn f() {}");
+        assert_no_attr_errors("#[repr(C)] struct S {}");
+        assert_no_attr_errors("#[inline(never)] fn g() {} #[repr(u8)] enum E { A }");
+        assert_no_attr_errors("struct S; impl S { #[inline(always)] fn f(&self) {} }");
+    }
+
+    #[test]
+    fn test_macro_invocation_is_deferred() {
+        let sess = test_session();
+        let item = parse_item("#[repr(C)] #[inline] m!();");
+        check_item(&sess, &item);
+        assert_eq!(sess.err_count(), 0);
+        assert!(validate_item_attrs(&sess, &item).iter().all(|result| result.is_valid()));
+    }
+
+    #[test]
+    fn test_inline_always_with_macro_in_body() {
+        let sess = test_session();
+        let item = parse_item("#[inline(always)] fn f() { println!(\"\") }");
+        check_item(&sess, &item);
+        assert_eq!(sess.err_count(), 0);
+    }
+
+    #[bench]
+    fn bench_check_item(b: &mut Bencher) {
+        let sess = test_session();
+        let item = parse_item("mod m { #[inline] #[cold] fn f() {} #[repr(C, align(8))] struct S; \
+                               #[repr(u8)] enum E { A } #[derive(Clone)] struct T; }");
+        b.iter(|| check_item(&sess, &item));
+        assert_eq!(sess.err_count(), 0);
+    }
+
+    #[test]
+    fn test_target_max_align() {
+        let mut sess = test_session();
+        sess.target.target.options.max_align = Some(8);
+        let item = parse_item("#[repr(align(16))] struct S(u8);");
+        assert_eq!(check_single_attribute(&sess, &item.attrs[0], &item)[0].message(),
+                   "invalid `repr(align)` attribute: larger than the target's maximum of 8 bytes");
+        let item = parse_item("#[repr(align(8))] struct S(u8);");
+        assert!(check_single_attribute(&sess, &item.attrs[0], &item).is_empty());
+    }
+
+    #[test]
+    fn test_check_item() {
+        let sess = test_session();
+        let item = parse_item("#[inline] mod m { #[repr(u8)] fn f() {} }");
+        check_item(&sess, &item);
+        assert_eq!(sess.err_count(), 2);
+    }
+
+    #[test]
+    fn test_check_item_after_early_lint_checks() {
+        let sess = test_session();
+        // What the early lint checks do; buffering a lint after this is a bug.
+        sess.buffered_lints.borrow_mut().take();
+        let item = parse_item("#[cold] #[inline(always)] fn f() {}");
+        check_item(&sess, &item);
+        assert_eq!(sess.err_count(), 0);
+    }
+
+    #[test]
+    fn test_empty_attribute_name() {
+        let sess = test_session();
+        let item = parse_item("#[inline] fn f() {}").map(|mut item| {
+            item.attrs[0].path.segments[0].identifier = ast::Ident::from_str("");
+            item
+        });
+        let results = validate_item_attrs(&sess, &item);
+        assert_eq!(results[0].errors, ["malformed attribute: empty name"]);
+    }
+
+    #[test]
+    fn test_word_attributes_are_not_parsed() {
+        let item = parse_item("#[inline] #[inline(always)] #[inline = \"always\"] fn f() {}");
+        assert!(item.attrs[0].tokens.is_empty());
+        assert!(meta_item_list(&item.attrs[0]).is_none());
+        assert_eq!(meta_item_list(&item.attrs[1]).map(|list| list.len()), Some(1));
+        assert!(meta_item_list(&item.attrs[2]).is_none());
+    }
+}
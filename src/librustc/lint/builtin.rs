@@ -246,6 +246,108 @@ declare_lint! {
     "raw pointer to an inference variable"
 }
 
+declare_lint! {
+    pub REPR_C_PACKED,
+    Allow,
+    "detects `#[repr(C, packed)]`, which both fixes the field order and removes padding"
+}
+
+declare_lint! {
+    pub REPR_PACKED_WITHOUT_C,
+    Allow,
+    "detects `#[repr(packed)]` structs that don't also have `#[repr(C)]`"
+}
+
+declare_lint! {
+    pub REPR_C_PRIVATE,
+    Allow,
+    "detects `#[repr(C)]` on private structs"
+}
+
+declare_lint! {
+    pub REPR_C_GENERIC,
+    Allow,
+    "detects `#[repr(C)]` structs whose layout depends on their type parameters"
+}
+
+declare_lint! {
+    pub REPR_C_UNION_NOT_COPY,
+    Allow,
+    "detects `#[repr(C)]` unions that don't derive `Copy`"
+}
+
+declare_lint! {
+    pub REPR_C_SINGLE_VARIANT,
+    Allow,
+    "detects `#[repr(C)]` enums with a single variant that carries data"
+}
+
+declare_lint! {
+    pub REPR_C_NON_EXHAUSTIVE,
+    Allow,
+    "detects `#[repr(C)]` enums that are also `#[non_exhaustive]`"
+}
+
+declare_lint! {
+    pub INLINE_EMPTY_FN,
+    Allow,
+    "detects `#[inline]` on functions with an empty body"
+}
+
+declare_lint! {
+    pub INLINE_TARGET_FEATURE,
+    Allow,
+    "detects functions that are both `#[inline(always)]` and `#[target_feature]`"
+}
+
+declare_lint! {
+    pub INLINE_NO_MANGLE,
+    Allow,
+    "detects functions that are both `#[inline]` and `#[no_mangle]`"
+}
+
+declare_lint! {
+    pub RECURSIVE_INLINE_ALWAYS,
+    Allow,
+    "detects `#[inline(always)]` on functions that obviously call themselves"
+}
+
+declare_lint! {
+    pub COLD_INLINE_ALWAYS,
+    Allow,
+    "detects functions that are both `#[cold]` and `#[inline(always)]`"
+}
+
+declare_lint! {
+    pub INLINE_REQUIRED_METHOD,
+    Allow,
+    "detects `#[inline]` on trait methods without a default body"
+}
+
+declare_lint! {
+    pub MUST_USE_UNIT,
+    Allow,
+    "detects `#[must_use]` on methods that return `()`"
+}
+
+declare_lint! {
+    pub REPR_ALIGN_ONE,
+    Allow,
+    "detects `#[repr(align(1))]`, which can't lower the alignment of a type"
+}
+
+declare_lint! {
+    pub REPR_ALIGN_REDUNDANT,
+    Allow,
+    "detects `#[repr(align(N))]` on types whose only field is already aligned to `N`"
+}
+
+declare_lint! {
+    pub NO_MANGLE_THREAD_LOCAL,
+    Allow,
+    "detects `#[no_mangle]` statics that are also `#[thread_local]`"
+}
+
 /// Does nothing as a lint pass, but registers some `Lint`s
 /// which are used by other parts of the compiler.
 #[derive(Copy, Clone)]
@@ -291,7 +393,24 @@ impl LintPass for HardwiredLints {
             UNUSED_MUT,
             COERCE_NEVER,
             SINGLE_USE_LIFETIME,
-            TYVAR_BEHIND_RAW_POINTER
+            TYVAR_BEHIND_RAW_POINTER,
+            REPR_C_PACKED,
+            REPR_PACKED_WITHOUT_C,
+            REPR_C_PRIVATE,
+            REPR_C_GENERIC,
+            REPR_C_UNION_NOT_COPY,
+            REPR_C_SINGLE_VARIANT,
+            REPR_C_NON_EXHAUSTIVE,
+            INLINE_NO_MANGLE,
+            INLINE_TARGET_FEATURE,
+            INLINE_EMPTY_FN,
+            RECURSIVE_INLINE_ALWAYS,
+            COLD_INLINE_ALWAYS,
+            INLINE_REQUIRED_METHOD,
+            MUST_USE_UNIT,
+            REPR_ALIGN_ONE,
+            REPR_ALIGN_REDUNDANT,
+            NO_MANGLE_THREAD_LOCAL
         )
     }
 }
@@ -1230,6 +1230,14 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
     saturating_float_casts: bool = (false, parse_bool, [TRACKED],
         "make float->int casts UB-free: numbers outside the integer type's range are clipped to \
          the max/min integer respectively, and NaN is mapped to 0"),
+    repr_conflict_summary: Option<usize> = (None, parse_opt_uint, [UNTRACKED],
+        "report conflicting repr hints as one summary warning once more than this many \
+         items have them"),
+    deny_repr_conflicts: bool = (false, parse_bool, [UNTRACKED],
+        "report conflicting repr hints as errors rather than warnings"),
+    misplaced_attrs_as_warnings: bool = (false, parse_bool, [UNTRACKED],
+        "report `#[inline]` on non-functions and `#[repr]` on items without a layout \
+         as warnings rather than errors"),
     lower_128bit_ops: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "rewrite operators on i128 and u128 into lang item calls (typically provided \
          by compiler-builtins) so translation doesn't need to support them,
@@ -906,6 +906,13 @@ pub fn phase_2_configure_and_expand<F>(sess: &Session,
         hir_map::Forest::new(hir_crate, &dep_graph)
     });
 
+    // Needs to go *before* the early lint checks, which emit the lints it
+    // buffers. The attribute errors are only reported after HIR lowering, by
+    // `check_crate` in `compile_input`.
+    time(time_passes,
+         "attribute lint checking",
+         || hir::check_attr::check_crate_lints(sess, &krate));
+
     time(time_passes,
          "early lint checks",
          || lint::check_ast_crate(sess, &krate));
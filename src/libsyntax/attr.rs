@@ -1008,16 +1008,8 @@ pub fn find_repr_attrs(diagnostic: &Handler, attr: &Attribute) -> Vec<ReprAttr>
                 if let Some(mi) = item.word() {
                     let word = &*mi.name().as_str();
                     let hint = match word {
-                        // Can't use "extern" because it's not a lexical identifier.
-                        "C" => Some(ReprExtern),
                         "packed" => Some(ReprPacked),
-                        "simd" => Some(ReprSimd),
-                        _ => match int_type_of_word(word) {
-                            Some(ity) => Some(ReprInt(ity)),
-                            None => {
-                                None
-                            }
-                        }
+                        _ => word_only_repr_hint(word),
                     };
 
                     if let Some(h) = hint {
@@ -1027,26 +1019,41 @@ pub fn find_repr_attrs(diagnostic: &Handler, attr: &Attribute) -> Vec<ReprAttr>
                 } else if let Some((name, value)) = item.name_value_literal() {
                     if name == "align" {
                         recognised = true;
-                        let mut align_error = None;
-                        if let ast::LitKind::Int(align, ast::LitIntType::Unsuffixed) = value.node {
-                            if align.is_power_of_two() {
-                                // rustc::ty::layout::Align restricts align to <= 2147483647
-                                if align <= 2147483647 {
-                                    acc.push(ReprAlign(align as u32));
-                                } else {
-                                    align_error = Some("larger than 2147483647");
-                                }
-                            } else {
-                                align_error = Some("not a power of two");
+                        let align = match value.node {
+                            ast::LitKind::Int(align, ast::LitIntType::Unsuffixed) => Ok(align),
+                            _ => Err("not an unsuffixed integer"),
+                        };
+                        push_repr_align(diagnostic, item, align, &mut acc);
+                    } else if name == "packed" {
+                        // Only `packed(1)` means anything yet, other values
+                        // are reported when checking attributes.
+                        if let ast::LitKind::Int(packing, ast::LitIntType::Unsuffixed) =
+                                value.node {
+                            recognised = true;
+                            if packing == 1 {
+                                acc.push(ReprPacked);
                             }
-                        } else {
-                            align_error = Some("not an unsuffixed integer");
-                        }
-                        if let Some(align_error) = align_error {
-                            span_err!(diagnostic, item.span, E0589,
-                                      "invalid `repr(align)` attribute: {}", align_error);
                         }
                     }
+                } else if item.check_name("align") && item.is_value_str() {
+                    // The older `align = "8"` form.
+                    recognised = true;
+                    let value = item.value_str().unwrap();
+                    let align = value.as_str().parse().map_err(|_| "not an integer");
+                    push_repr_align(diagnostic, item, align, &mut acc);
+                } else if item.check_name("align") {
+                    // Any other form, e.g. `align = 8` or `align(8, 16)`.
+                    recognised = true;
+                    let align = Err("the alignment must be written as `align(N)`");
+                    push_repr_align(diagnostic, item, align, &mut acc);
+                }
+                if !recognised {
+                    // Arguments given to a word-only hint, e.g. `repr(C(x))`, are
+                    // reported when checking attributes, so just use the hint here.
+                    if let Some(hint) = item.name().and_then(|n| word_only_repr_hint(&n.as_str())) {
+                        recognised = true;
+                        acc.push(hint);
+                    }
                 }
                 if !recognised {
                     // Not a word we recognize
@@ -1059,6 +1066,41 @@ pub fn find_repr_attrs(diagnostic: &Handler, attr: &Attribute) -> Vec<ReprAttr>
     acc
 }
 
+/// Validate the value given to a `repr(align)` hint and add it to `acc`.
+fn push_repr_align(diagnostic: &Handler,
+                   item: &NestedMetaItem,
+                   align: Result<u128, &'static str>,
+                   acc: &mut Vec<ReprAttr>) {
+    let align = align.and_then(|align| {
+        if !align.is_power_of_two() {
+            Err("not a power of two")
+        } else if align > 2147483647 {
+            // rustc::ty::layout::Align restricts align to <= 2147483647
+            Err("larger than 2147483647")
+        } else {
+            Ok(align as u32)
+        }
+    });
+    match align {
+        Ok(align) => acc.push(ReprAlign(align)),
+        Err(align_error) => {
+            span_err!(diagnostic, item.span, E0589,
+                      "invalid `repr(align)` attribute: {}", align_error);
+        }
+    }
+}
+
+/// The representation hints that are only valid as a plain word, i.e.
+/// without any arguments.
+fn word_only_repr_hint(s: &str) -> Option<ReprAttr> {
+    match s {
+        // Can't use "extern" because it's not a lexical identifier.
+        "C" => Some(ReprExtern),
+        "simd" => Some(ReprSimd),
+        _ => int_type_of_word(s).map(ReprInt),
+    }
+}
+
 fn int_type_of_word(s: &str) -> Option<IntType> {
     match s {
         "i8" => Some(SignedInt(ast::IntTy::I8)),
@@ -34,8 +34,9 @@ use errors::{DiagnosticBuilder, Handler, FatalError};
 use visit::{self, FnKind, Visitor};
 use parse::ParseSess;
 use symbol::{keywords, Symbol};
+use util::lev_distance::lev_distance;
 
-use std::{env, path};
+use std::{cmp, env, path};
 
 macro_rules! set {
     (proc_macro) => {{
@@ -447,6 +448,9 @@ declare_features! (
 
     // Termination trait in main (RFC 1937)
     (active, termination_trait, "1.24.0", Some(43301)),
+
+    // Allows `#[repr(packed(N))]` attribute on structs and unions
+    (active, repr_packed, "1.24.0", Some(33158)),
 );
 
 declare_features! (
@@ -1089,18 +1093,34 @@ impl<'a> Context<'a> {
             // feature gate checking. Macro gating runs
             // before the plugin attributes are registered
             // so we skip this then
-            if !is_macro {
-                gate_feature!(self, custom_attribute, attr.span,
-                              &format!("The attribute `{}` is currently \
-                                        unknown to the compiler and \
-                                        may have meaning \
-                                        added to it in the future",
-                                       attr.path));
+            if !is_macro && !self.features.custom_attribute && !attr.span.allows_unstable() {
+                let explain = format!("The attribute `{}` is currently \
+                                       unknown to the compiler and \
+                                       may have meaning \
+                                       added to it in the future",
+                                      attr.path);
+                let mut err = feature_err(self.parse_sess, "custom_attribute", attr.span,
+                                          GateIssue::Language, &explain);
+                if let Some(builtin) = find_similar_builtin_attr(&name) {
+                    err.help(&format!("did you mean `{}`?", builtin));
+                }
+                err.emit();
             }
         }
     }
 }
 
+/// The built-in attribute whose name is closest to `name`, if one is close
+/// enough to be a likely misspelling of it.
+fn find_similar_builtin_attr(name: &str) -> Option<&'static str> {
+    let max_dist = cmp::max(name.len(), 3) / 3;
+    BUILTIN_ATTRIBUTES.iter()
+        .map(|&(builtin, _, _)| (lev_distance(name, builtin), builtin))
+        .filter(|&(dist, _)| dist <= max_dist)
+        .min_by_key(|&(dist, _)| dist)
+        .map(|(_, builtin)| builtin)
+}
+
 pub fn check_attribute(attr: &ast::Attribute, parse_sess: &ParseSess, features: &Features) {
     let cx = Context { features: features, parse_sess: parse_sess, plugin_attributes: &[] };
     cx.check_attribute(attr, true);
@@ -1514,6 +1534,35 @@ impl<'a> Visitor<'a> for PostExpansionVisitor<'a> {
                                                "the struct `#[repr(align(u16))]` attribute \
                                                 is experimental");
                         }
+                        if item.check_name("packed") && !item.is_word() {
+                            gate_feature_post!(&self, repr_packed, attr.span,
+                                               "the `#[repr(packed(n))]` attribute \
+                                                is experimental");
+                        }
+                    }
+                }
+            }
+
+            ast::ItemKind::Union(..) => {
+                if let Some(attr) = attr::find_by_name(&i.attrs[..], "repr") {
+                    for item in attr.meta_item_list().unwrap_or_else(Vec::new) {
+                        if item.check_name("packed") && !item.is_word() {
+                            gate_feature_post!(&self, repr_packed, attr.span,
+                                               "the `#[repr(packed(n))]` attribute \
+                                                is experimental");
+                        }
+                    }
+                }
+            }
+
+            ast::ItemKind::Enum(..) => {
+                if let Some(attr) = attr::find_by_name(&i.attrs[..], "repr") {
+                    for item in attr.meta_item_list().unwrap_or_else(Vec::new) {
+                        if item.check_name("align") {
+                            gate_feature_post!(&self, repr_align, attr.span,
+                                               "the enum `#[repr(align(u16))]` attribute \
+                                                is experimental");
+                        }
                     }
                 }
             }
@@ -0,0 +1,35 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// aux-build:consume-attrs.rs
+// ignore-stage1
+
+// Attributes are checked after attribute macros are expanded, so only the
+// ones still on an item afterwards are reported.
+
+#![feature(proc_macro)]
+
+extern crate consume_attrs;
+
+use consume_attrs::{consume, passthrough};
+
+#[consume]
+#[inline]
+#[repr(u8)]
+fn consumed() {}
+
+#[passthrough]
+#[inline] //~ ERROR attribute should be applied to function
+struct Kept;
+
+fn main() {
+    let _ = Consumed;
+    let _ = Kept;
+}
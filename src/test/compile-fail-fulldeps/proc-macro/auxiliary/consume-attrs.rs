@@ -0,0 +1,31 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// force-host
+// no-prefer-dynamic
+
+#![crate_type = "proc-macro"]
+#![feature(proc_macro)]
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+
+/// Replaces the item, and any attributes following this one, with a unit
+/// struct.
+#[proc_macro_attribute]
+pub fn consume(_attr: TokenStream, _input: TokenStream) -> TokenStream {
+    "struct Consumed;".parse().unwrap()
+}
+
+#[proc_macro_attribute]
+pub fn passthrough(_attr: TokenStream, input: TokenStream) -> TokenStream {
+    input
+}
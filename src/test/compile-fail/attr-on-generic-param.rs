@@ -0,0 +1,26 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Check that `#[inline]` and `#[repr]` are rejected on generic parameters.
+
+#![feature(generic_param_attrs)]
+#![allow(dead_code)]
+
+struct S<#[inline] T>(T); //~ ERROR attribute should be applied to function
+
+struct L<#[repr(C)] 'a>(&'a u8); //~ ERROR attribute should be applied to struct, enum or union
+
+fn f<#[inline] T>(_: T) {} //~ ERROR attribute should be applied to function
+
+struct D<#[repr(C)] T = u8>(T); //~ ERROR attribute should be applied to struct, enum or union
+
+struct E<T, #[inline] U = T>(T, U); //~ ERROR attribute should be applied to function
+
+fn main() {}
@@ -0,0 +1,38 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Test that `#[rustc_*]` attributes are gated wherever attributes can go,
+// not just on items.
+
+#![allow(dead_code)]
+
+trait Tr {
+    #[rustc_foo] //~ ERROR attributes with the prefix `rustc_` are reserved
+    fn f();
+}
+
+struct S;
+
+impl S {
+    #[rustc_foo] //~ ERROR attributes with the prefix `rustc_` are reserved
+    fn f() {}
+}
+
+extern {
+    #[rustc_foo] //~ ERROR attributes with the prefix `rustc_` are reserved
+    fn g();
+}
+
+struct Fields {
+    #[rustc_foo] //~ ERROR attributes with the prefix `rustc_` are reserved
+    x: u8,
+}
+
+fn main() {}
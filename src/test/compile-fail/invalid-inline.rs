@@ -21,8 +21,23 @@ fn b() {
 fn c() {
 }
 
+#[inline(always, never)] //~ ERROR expected one argument
+fn d() {
+}
+
+#[inline(always(x))] //~ ERROR invalid argument
+fn e() {
+}
+
+// Never called, so only checking attributes can catch it.
+#[inline(foo)] //~ ERROR invalid argument
+fn unused() {
+}
+
 fn main() {
     a();
     b();
     c();
+    d();
+    e();
 }
@@ -0,0 +1,36 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(cold_inline_always)]
+#![allow(dead_code)]
+
+#[cold]
+#[inline(always)] //~ ERROR `#[inline(always)]` contradicts `#[cold]`
+fn both() {}
+
+#[cold]
+#[inline]
+fn cold_inline() {}
+
+struct S;
+
+impl S {
+    #[cold]
+    #[inline(always)] //~ ERROR `#[inline(always)]` contradicts `#[cold]`
+    fn method(&self) {}
+}
+
+trait T {
+    #[cold]
+    #[inline(always)] //~ ERROR `#[inline(always)]` contradicts `#[cold]`
+    fn provided(&self) {}
+}
+
+fn main() {}
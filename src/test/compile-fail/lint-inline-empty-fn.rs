@@ -0,0 +1,32 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(inline_empty_fn)]
+#![allow(dead_code)]
+
+#[inline] //~ ERROR this `#[inline]` function has an empty body
+fn f() {}
+
+#[inline]
+fn g() -> u8 { 0 }
+
+struct S;
+
+impl S {
+    #[inline(always)] //~ ERROR this `#[inline]` function has an empty body
+    fn h(&self) {}
+}
+
+trait T {
+    #[inline] //~ ERROR this `#[inline]` function has an empty body
+    fn i(&self) {}
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(inline_no_mangle)]
+#![allow(dead_code)]
+
+#[inline] //~ ERROR this function is `#[no_mangle]`, so its symbol is still emitted
+#[no_mangle]
+pub fn both() {}
+
+#[no_mangle]
+#[inline(always)] //~ ERROR this function is `#[no_mangle]`, so its symbol is still emitted
+pub fn both_reversed() {}
+
+#[inline]
+pub fn inline_only() {}
+
+#[no_mangle]
+pub fn no_mangle_only() {}
+
+fn main() {}
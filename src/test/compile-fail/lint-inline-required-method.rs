@@ -0,0 +1,22 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(inline_required_method)]
+#![allow(dead_code)]
+
+trait Tr {
+    #[inline] //~ ERROR `#[inline]` has no effect on a trait method without a body
+    fn f(&self);
+
+    #[inline]
+    fn g(&self) {}
+}
+
+fn main() {}
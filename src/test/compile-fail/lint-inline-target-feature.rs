@@ -0,0 +1,31 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(inline_target_feature)]
+#![feature(target_feature)]
+#![allow(dead_code)]
+
+#[inline(always)] //~ ERROR this function has a `#[target_feature]`
+#[target_feature = "+avx"]
+unsafe fn both() {}
+
+#[inline]
+#[target_feature = "+avx"]
+unsafe fn inline_hint() {}
+
+struct S;
+
+impl S {
+    #[target_feature = "+avx"]
+    #[inline(always)] //~ ERROR this function has a `#[target_feature]`
+    unsafe fn method(&self) {}
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(fn_must_use)]
+#![deny(must_use_unit)]
+#![allow(dead_code)]
+
+trait T {
+    #[must_use] //~ ERROR `#[must_use]` has no effect on a method that returns `()`
+    fn f(&self);
+
+    #[must_use]
+    fn g(&self) -> u8;
+}
+
+struct S;
+
+impl S {
+    #[must_use = "pointless"] //~ ERROR `#[must_use]` has no effect on a method that returns `()`
+    fn h(&self) -> () {}
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(thread_local)]
+#![deny(no_mangle_thread_local)]
+
+#[no_mangle] //~ ERROR this `#[no_mangle]` static is thread-local
+#[thread_local]
+pub static FOO: u32 = 3;
+
+#[no_mangle]
+pub static BAR: u32 = 3;
+
+fn main() {}
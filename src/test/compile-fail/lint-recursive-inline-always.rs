@@ -0,0 +1,47 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(recursive_inline_always)]
+#![allow(dead_code, unconditional_recursion)]
+
+#[inline(always)] //~ ERROR `#[inline(always)]` can't be honoured for a function that calls itself
+fn f() { f() }
+
+#[inline(always)] //~ ERROR `#[inline(always)]` can't be honoured for a function that calls itself
+fn countdown(n: u32) -> u32 {
+    if n == 0 { 0 } else { countdown(n - 1) }
+}
+
+#[inline]
+fn g() { g() }
+
+#[inline(always)]
+fn h() {
+    // The inner `h` is a different function.
+    fn h() {}
+    h();
+}
+
+#[inline(always)]
+fn i() {
+    let i = || ();
+    i();
+}
+
+#[inline(always)]
+fn j() {
+    let call = |j: fn()| j();
+    call(main);
+}
+
+#[inline(always)]
+fn k(k: fn()) { k() }
+
+fn main() {}
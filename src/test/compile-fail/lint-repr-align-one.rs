@@ -0,0 +1,25 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(attr_literals)]
+#![feature(repr_align)]
+#![deny(repr_align_one)]
+#![allow(dead_code)]
+
+#[repr(align(1))] //~ ERROR `align(1)` can only raise the alignment of a type
+struct S(u64);
+
+#[repr(align(8))]
+struct T(u32, u32);
+
+#[repr(align(1))] //~ ERROR `align(1)` can only raise the alignment of a type
+union U { a: u64 }
+
+fn main() {}
@@ -0,0 +1,28 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(attr_literals)]
+#![feature(repr_align)]
+#![deny(repr_align_redundant)]
+#![allow(dead_code)]
+
+#[repr(align(2))] //~ ERROR `align(2)` has no effect, the only field of this type is already
+struct S(u16);
+
+#[repr(align(2))] //~ ERROR `align(2)` has no effect, the only field of this type is already
+union U { a: i16 }
+
+#[repr(align(16))]
+struct Raised(u16);
+
+#[repr(align(2))]
+struct TwoFields(u16, u8);
+
+fn main() {}
@@ -0,0 +1,26 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(repr_c_generic)]
+#![allow(dead_code)]
+
+#[repr(C)] //~ ERROR the layout of this `repr(C)` struct depends on
+struct Generic<T>(T);
+
+#[repr(C)] //~ ERROR the layout of this `repr(C)` struct depends on
+struct Mixed<'a, T: 'a>(&'a T);
+
+#[repr(C)]
+struct LifetimeOnly<'a>(&'a u8);
+
+#[repr(C)]
+struct Plain(u8);
+
+fn main() {}
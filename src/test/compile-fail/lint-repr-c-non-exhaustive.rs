@@ -0,0 +1,29 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(repr_c_non_exhaustive)]
+#![feature(non_exhaustive)]
+#![allow(dead_code)]
+
+#[repr(C)] //~ ERROR this `repr(C)` enum is also `#[non_exhaustive]`
+#[non_exhaustive]
+pub enum Both { A, B }
+
+#[non_exhaustive]
+#[repr(C)] //~ ERROR this `repr(C)` enum is also `#[non_exhaustive]`
+pub enum Reversed { A, B }
+
+#[repr(C)]
+pub enum Exhaustive { A, B }
+
+#[non_exhaustive]
+pub enum Rust { A, B }
+
+fn main() {}
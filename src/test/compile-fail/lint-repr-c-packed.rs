@@ -0,0 +1,27 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(repr_c_packed)]
+#![allow(dead_code)]
+
+#[repr(C, packed)] //~ ERROR `repr(C, packed)` keeps the declared field order
+struct A(u8, u32);
+
+#[repr(C, packed)]
+#[allow(repr_c_packed)]
+struct B(u8, u32);
+
+#[repr(C)]
+struct C(u8, u32);
+
+#[repr(packed)]
+struct D(u8, u32);
+
+fn main() {}
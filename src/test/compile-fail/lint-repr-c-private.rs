@@ -0,0 +1,28 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(repr_c_private)]
+#![allow(dead_code)]
+
+#[repr(C)] //~ ERROR this `repr(C)` struct is private
+struct Private { a: u8, b: u32 }
+
+#[repr(C)]
+pub struct Public { a: u8, b: u32 }
+
+#[repr(C)]
+pub(crate) struct CrateVisible { a: u8, b: u32 }
+
+mod m {
+    #[repr(C)] //~ ERROR this `repr(C)` struct is private
+    struct Nested(u8, u32);
+}
+
+fn main() {}
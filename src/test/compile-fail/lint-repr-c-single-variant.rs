@@ -0,0 +1,26 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(repr_c_single_variant)]
+#![allow(dead_code)]
+
+#[repr(C)] //~ ERROR this `repr(C)` enum has a single variant with data
+enum A { X(u32) }
+
+#[repr(C)] //~ ERROR this `repr(C)` enum has a single variant with data
+enum B { X { a: u8, b: u16 } }
+
+#[repr(C)]
+enum Unit { X }
+
+#[repr(C)]
+enum Two { X(u32), Y }
+
+fn main() {}
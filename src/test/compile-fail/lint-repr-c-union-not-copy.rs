@@ -0,0 +1,32 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(repr_c_union_not_copy)]
+#![allow(dead_code)]
+
+#[repr(C)] //~ ERROR this `repr(C)` union doesn't derive `Copy`
+union NoDerive {
+    a: u32,
+    b: f32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+union Derived {
+    a: u32,
+    b: f32,
+}
+
+union NotC {
+    a: u32,
+    b: f32,
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(attr_literals, repr_packed)]
+#![deny(repr_packed_without_c)]
+#![allow(dead_code)]
+
+#[repr(packed)] //~ ERROR the field order of a `repr(packed)` struct is unspecified
+struct S(u8, u16);
+
+#[repr(packed(1))] //~ ERROR the field order of a `repr(packed)` struct is unspecified
+struct T { a: u8, b: u32 }
+
+#[repr(C, packed)]
+struct U(u8, u16);
+
+#[repr(C)]
+#[repr(packed)]
+struct V(u8, u16);
+
+fn main() {}
@@ -32,8 +32,16 @@ pub static BAR: u64 = 1;
 #[no_mangle]
 static PRIVATE_BAR: u64 = 1; //~ ERROR static is marked #[no_mangle], but not exported
 
+// `pub` alone isn't enough, the item has to be reachable from the crate root.
+mod private {
+    #[no_mangle]
+    pub fn baz() { //~ ERROR function is marked #[no_mangle], but not exported
+    }
+}
+
 
 fn main() {
     foo();
     bar();
+    private::baz();
 }
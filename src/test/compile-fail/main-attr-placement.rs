@@ -0,0 +1,27 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(main)]
+#![feature(lang_items)]
+#![allow(dead_code)]
+
+#[main] //~ WARN attribute should be applied to a function
+struct S;
+
+#[main] //~ WARN attribute should be applied to a function
+mod m {}
+
+#[lang] //~ ERROR malformed `lang` attribute
+fn f() {}
+
+#[lang(sized)] //~ ERROR malformed `lang` attribute
+fn g() {}
+
+fn main() {}
@@ -0,0 +1,25 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z misplaced-attrs-as-warnings
+
+// Hints that are wrong for the type they're on stay errors, as layout would
+// otherwise have to cope with them.
+
+#![feature(repr_simd)]
+#![allow(dead_code)]
+
+#[repr(simd)] //~ ERROR attribute should be applied to struct
+enum E { A }
+
+#[repr(u8)] //~ ERROR attribute should be applied to enum
+struct S(u8);
+
+fn main() {}
@@ -20,4 +20,13 @@ struct B(i32);
 #[repr(align(4294967296))] //~ ERROR: invalid `repr(align)` attribute: larger than 2147483647
 struct C(i32);
 
+#[repr(align = "eight")] //~ ERROR: invalid `repr(align)` attribute: not an integer
+struct D(i32);
+
+#[repr(align = "12")] //~ ERROR: invalid `repr(align)` attribute: not a power of two
+struct E(i32);
+
+#[repr(align = 8)] //~ ERROR: invalid `repr(align)` attribute: the alignment must be written as
+struct F(i32);
+
 fn main() {}
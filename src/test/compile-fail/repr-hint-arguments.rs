@@ -0,0 +1,26 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(repr_simd)]
+#![allow(dead_code)]
+
+#[repr(C(x))] //~ ERROR the `C` representation hint takes no arguments
+struct A(u8);
+
+#[repr(u8(x))] //~ ERROR the `u8` representation hint takes no arguments
+enum B { X, Y }
+
+#[repr(C, u16(y))] //~ ERROR the `u16` representation hint takes no arguments
+enum C { X, Y }
+
+#[repr(simd(x))] //~ ERROR the `simd` representation hint takes no arguments
+struct D(f32, f32, f32, f32);
+
+fn main() {}
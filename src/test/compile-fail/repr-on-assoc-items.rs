@@ -0,0 +1,37 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+trait Tr {
+    #[repr(C)] //~ ERROR attribute should be applied to struct, enum or union
+    type A;
+
+    #[repr(u8)] //~ ERROR attribute should be applied to enum
+    const B: u8;
+
+    #[inline]
+    fn f(&self) {}
+}
+
+struct S;
+
+impl Tr for S {
+    #[repr(C)] //~ ERROR attribute should be applied to struct, enum or union
+    type A = u8;
+
+    #[inline] //~ ERROR attribute should be applied to function
+    const B: u8 = 0;
+
+    #[inline]
+    fn f(&self) {}
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(attr_literals, repr_packed)]
+#![allow(dead_code)]
+
+#[repr(packed(0))] //~ ERROR invalid `repr(packed)` attribute: `packed(0)`
+struct Zero(u8, u32);
+
+#[repr(packed(3))] //~ ERROR invalid `repr(packed)` attribute: `packed(3)`
+struct NotAPowerOfTwo(u8, u32);
+
+#[repr(packed(4))] //~ ERROR invalid `repr(packed)` attribute: `packed(4)`
+struct Unsupported(u8, u32);
+
+#[repr(packed(1))]
+struct One(u8, u32);
+
+fn main() {}
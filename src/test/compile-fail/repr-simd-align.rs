@@ -0,0 +1,25 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A non-power-of-two alignment is rejected on SIMD types like anywhere else,
+// and a valid one combines with `simd` without complaint.
+
+#![feature(attr_literals)]
+#![feature(repr_align)]
+#![feature(repr_simd)]
+#![allow(dead_code)]
+
+#[repr(simd, align(3))] //~ ERROR invalid `repr(align)` attribute: not a power of two
+struct A(f32, f32, f32);
+
+#[repr(simd, align(16))]
+struct B(f32, f32, f32, f32);
+
+fn main() {}
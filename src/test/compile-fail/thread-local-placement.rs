@@ -0,0 +1,37 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(thread_local)]
+#![allow(dead_code)]
+
+#[thread_local] //~ ERROR attribute should be applied to a static
+fn f() {}
+
+#[thread_local] //~ ERROR attribute should be applied to a static
+const C: u8 = 0;
+
+#[thread_local] //~ ERROR attribute should be applied to a static
+struct S;
+
+#[thread_local(always)] //~ ERROR the `thread_local` attribute takes no arguments
+static A: u8 = 0;
+
+#[thread_local = "yes"] //~ ERROR the `thread_local` attribute takes no arguments
+static B: u8 = 0;
+
+#[thread_local]
+static OK: u8 = 0;
+
+extern {
+    #[thread_local]
+    static EXTERN_OK: u8;
+}
+
+fn main() {}
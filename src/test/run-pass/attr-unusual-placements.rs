@@ -0,0 +1,45 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Attributes in places the attribute checks don't treat as items, like the
+// binders of where-clause predicates, fields or statements, are left alone:
+// no error and no crash.
+
+#![feature(generic_param_attrs, rustc_attrs, stmt_expr_attributes)]
+#![allow(dead_code)]
+
+struct S<T> where T: for<#[rustc_lt_where] 'a> Fn(&'a u8) {
+    #[rustc_field]
+    f: T,
+}
+
+enum E {
+    #[rustc_variant]
+    A(#[rustc_tuple_field] u8),
+}
+
+trait Tr where Self: for<#[rustc_lt_trait_where] 'b> PartialEq<&'b u8> {}
+
+impl<T> S<T> where T: for<#[rustc_lt_impl_where] 'c> Fn(&'c u8) {
+    fn call(&self) {
+        #[rustc_stmt]
+        let x = 0;
+        (self.f)(&x);
+        match x {
+            #[rustc_arm]
+            _ => #[rustc_expr] (),
+        }
+    }
+}
+
+fn main() {
+    let s = S { f: |_: &u8| {} };
+    s.call();
+}
@@ -0,0 +1,29 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(attr_literals)]
+#![feature(repr_align)]
+
+use std::mem;
+
+#[repr(u8, align(8))]
+#[allow(dead_code)]
+enum Tag { A, B }
+
+#[repr(align(16))]
+#[allow(dead_code)]
+enum WithData { A(u8), B(u32) }
+
+pub fn main() {
+    assert_eq!(mem::align_of::<Tag>(), 8);
+    assert_eq!(mem::size_of::<Tag>(), 8);
+    assert_eq!(mem::align_of::<WithData>(), 16);
+    assert_eq!(mem::size_of::<WithData>(), 16);
+}
@@ -0,0 +1,27 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(attr_literals)]
+#![feature(repr_align)]
+
+use std::mem;
+
+// The older string form of the align hint.
+#[repr(align = "8")]
+struct A(i32);
+
+#[repr(align(8))]
+struct B(i32);
+
+fn main() {
+    assert_eq!(mem::align_of::<A>(), 8);
+    assert_eq!(mem::align_of::<B>(), 8);
+    assert_eq!(mem::size_of::<A>(), mem::size_of::<B>());
+}
@@ -0,0 +1,23 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Attributes on an item macro invocation are dropped when it's expanded, so
+// they aren't reported as misplaced.
+
+#![allow(dead_code)]
+
+macro_rules! make_struct {
+    () => { struct S(u8, u32); }
+}
+
+#[repr(C)]
+make_struct!();
+
+pub fn main() {}
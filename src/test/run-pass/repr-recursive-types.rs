@@ -0,0 +1,25 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Checking the representation hints only looks at the fields as written, so
+// a type that contains itself is no different from any other.
+
+#![deny(warnings)]
+
+#[repr(C)]
+struct List {
+    next: Option<Box<List>>,
+    value: u32,
+}
+
+fn main() {
+    let list = List { next: Some(Box::new(List { next: None, value: 1 })), value: 0 };
+    assert_eq!(list.next.map(|next| next.value), Some(1));
+}
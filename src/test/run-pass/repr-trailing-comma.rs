@@ -0,0 +1,29 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A trailing comma in a repr list is just a trailing comma.
+
+#![deny(warnings)]
+
+use std::mem;
+
+#[repr(C,)]
+struct S(u8, u32, u8);
+
+#[repr(u8,)]
+enum E { A, B }
+
+fn main() {
+    let _ = S(0, 0, 0);
+    let _ = E::B;
+    assert_eq!(mem::size_of::<S>(), 12);
+    assert_eq!(mem::size_of::<E>(), 1);
+    assert_eq!(E::A as u8, 0);
+}
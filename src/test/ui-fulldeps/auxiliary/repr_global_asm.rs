@@ -0,0 +1,52 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// force-host
+
+#![feature(plugin_registrar, rustc_private)]
+#![crate_type = "dylib"]
+
+extern crate syntax;
+extern crate syntax_pos;
+extern crate rustc_plugin;
+
+use syntax::ast;
+use syntax::ext::base::{ExtCtxt, MacEager, MacResult};
+use syntax::ext::build::AstBuilder;
+use syntax::ptr::P;
+use syntax::symbol::Symbol;
+use syntax::tokenstream::TokenTree;
+use syntax::util::small_vector::SmallVector;
+use syntax_pos::Span;
+use rustc_plugin::Registry;
+
+#[plugin_registrar]
+pub fn plugin_registrar(reg: &mut Registry) {
+    reg.register_macro("repr_global_asm", expand);
+}
+
+// Attributes written on a `global_asm!` invocation are dropped during
+// expansion, so build a global asm item that carries `#[repr(C)]` by hand.
+fn expand(cx: &mut ExtCtxt, sp: Span, _: &[TokenTree]) -> Box<MacResult + 'static> {
+    let c = cx.meta_list_item_word(sp, Symbol::intern("C"));
+    let repr = cx.meta_list(sp, Symbol::intern("repr"), vec![c]);
+    MacEager::items(SmallVector::one(P(ast::Item {
+        ident: ast::Ident::with_empty_ctxt(Symbol::intern("")),
+        attrs: vec![cx.attribute(sp, repr)],
+        id: ast::DUMMY_NODE_ID,
+        node: ast::ItemKind::GlobalAsm(P(ast::GlobalAsm {
+            asm: Symbol::intern(""),
+            ctxt: cx.backtrace(),
+        })),
+        vis: ast::Visibility::Inherited,
+        span: sp,
+        tokens: None,
+    })))
+}
@@ -0,0 +1,22 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+#[inlin] //~ ERROR The attribute `inlin` is currently unknown to the compiler
+fn f() {}
+
+#[repr_c] //~ ERROR The attribute `repr_c` is currently unknown to the compiler
+struct S;
+
+#[frobnicate] //~ ERROR The attribute `frobnicate` is currently unknown to the compiler
+fn g() {}
+
+fn main() {}
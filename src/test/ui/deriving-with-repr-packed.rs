@@ -38,4 +38,10 @@ struct Y(usize);
 #[repr(packed)]
 struct X(Y);
 
+#[derive(Debug)]
+//~^ ERROR #[derive] can't be used on a non-Copy #[repr(packed)] struct
+//~| hard error
+#[repr(packed)]
+struct Z(u64);
+
 fn main() {}
@@ -12,4 +12,7 @@
 #[repr(align(64))] //~ error: the struct `#[repr(align(u16))]` attribute is experimental
 struct Foo(u64, u64);
 
+#[repr(align(8))] //~ error: the enum `#[repr(align(u16))]` attribute is experimental
+enum Bar { A, B }
+
 fn main() {}
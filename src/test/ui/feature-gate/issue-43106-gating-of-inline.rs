@@ -25,6 +25,7 @@ mod inline {
     //~^ ERROR attribute should be applied to function
 
     #[inline = "2100"] fn f() { }
+    //~^ WARN the `inline` attribute does not take a value
 
     #[inline = "2100"] struct S;
     //~^ ERROR attribute should be applied to function
@@ -0,0 +1,28 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// must-compile-successfully
+
+#![allow(dead_code)]
+
+#[inline = "always"] //~ WARN the `inline` attribute does not take a value
+fn f() {}
+
+#[inline = "never"] //~ WARN the `inline` attribute does not take a value
+fn g() {}
+
+fn h() {
+    #![inline = "yes"] //~ WARN the `inline` attribute does not take a value
+}
+
+#[inline(always)]
+fn i() {}
+
+fn main() {}
@@ -0,0 +1,24 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+// must-compile-successfully
+
+#![allow(dead_code)]
+
+#[repr(u8)]
+/// The discriminant type is given twice.
+#[repr(u16)]
+enum A { X }
+
+#[repr(C)]
+/// A doc comment doesn't count as a hint of its own.
+#[repr(packed)]
+struct B(u8, u32);
+
+fn main() {}
@@ -0,0 +1,24 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+// must-compile-successfully
+
+#![feature(repr_simd)]
+#![allow(dead_code)]
+
+#[repr(u8, u16)]
+enum Ints { A }
+
+#[repr(C, simd)]
+struct Simd(f32, f32);
+
+#[repr(C, u8)]
+enum Fieldless { A, B }
+
+fn main() {}
@@ -0,0 +1,23 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+// must-compile-successfully
+
+#![warn(repr_c_packed)]
+#![allow(dead_code)]
+
+#[repr(C)]
+#[cfg_attr(all(), repr(packed))]
+struct S(u8, u32);
+
+#[repr(u8)]
+#[cfg_attr(all(), repr(u16))]
+enum E { X }
+
+fn main() {}
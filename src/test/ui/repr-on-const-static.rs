@@ -0,0 +1,22 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code)]
+
+#[repr(C)] //~ ERROR attribute should be applied to struct, enum or union
+const X: u8 = 0;
+
+#[repr(C)] //~ ERROR attribute should be applied to struct, enum or union
+static Y: u8 = 0;
+
+#[repr(C)] //~ ERROR attribute should be applied to struct, enum or union
+static Z: (u8, [u16; 2], &str) = (0, [1, 2], "");
+
+fn main() {}
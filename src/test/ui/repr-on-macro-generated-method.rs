@@ -0,0 +1,29 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Attributes on associated items produced by a macro are checked against the
+// item they end up on, and the error points back at the invocation.
+
+#![allow(dead_code)]
+
+struct S;
+
+macro_rules! method {
+    () => {
+        impl S {
+            #[repr(C)] //~ ERROR attribute should be applied to struct, enum or union
+            fn f() {}
+        }
+    }
+}
+
+method!();
+
+fn main() {}
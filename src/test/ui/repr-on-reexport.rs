@@ -0,0 +1,25 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![allow(dead_code, unused_imports)]
+
+mod foo {
+    #[repr(C)]
+    pub struct Bar;
+    pub fn baz() {}
+}
+
+#[repr(C)] //~ ERROR attribute should be applied to struct, enum or union
+pub use foo::Bar;
+
+#[inline] //~ ERROR attribute should be applied to function
+pub use foo::baz;
+
+fn main() {}
@@ -0,0 +1,19 @@
+// Copyright 2017 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(trait_alias)]
+
+#[repr(C)] //~ ERROR attribute should be applied to struct, enum or union
+trait Alias = Clone; //~ ERROR E0645
+
+#[inline] //~ ERROR attribute should be applied to function
+trait InlineAlias = Clone; //~ ERROR E0645
+
+fn main() {}